@@ -0,0 +1,181 @@
+//! Measures the allocations and time spent updating the meshes of 500 animated skeletons.
+//!
+//! Run with `cargo bench --bench mesh_update`. Mesh update costs are measured as the difference
+//! between frames using [`SpineDrawer::Combined`] or [`SpineDrawer::Separated`] and frames using
+//! [`SpineDrawer::None`], which still advances animations but skips the meshes.
+//!
+//! Since mesh buffers are reused, mesh updates must allocate less than the size of the meshes
+//! they write, which building new meshes every frame would allocate at the very least.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+use bevy::{prelude::*, render::mesh::Indices, time::TimeUpdateStrategy};
+use bevy_spine::{prelude::*, SpineDrawer};
+
+const SKELETONS: usize = 500;
+const FRAMES: usize = 100;
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+#[derive(Debug, Clone, Copy)]
+struct FrameStats {
+    allocations: f64,
+    allocated_bytes: f64,
+    time: Duration,
+}
+
+fn main() {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin {
+            file_path: concat!(env!("CARGO_MANIFEST_DIR"), "/assets").to_owned(),
+            ..Default::default()
+        },
+        TransformPlugin,
+        HierarchyPlugin,
+    ))
+    .init_asset::<Mesh>()
+    .init_asset::<Image>()
+    .init_asset::<Shader>()
+    .add_plugins(SpinePlugin)
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        1. / 60.,
+    )))
+    .add_systems(Update, on_spawn.in_set(SpineSet::OnReady));
+
+    let skeleton = {
+        let asset_server = app.world().resource::<AssetServer>();
+        SkeletonData::new_from_json(
+            asset_server.load("spineboy/export/spineboy-pro.json"),
+            asset_server.load("spineboy/export/spineboy-pma.atlas"),
+        )
+    };
+    let skeleton_handle = app
+        .world_mut()
+        .resource_mut::<Assets<SkeletonData>>()
+        .add(skeleton);
+    for _ in 0..SKELETONS {
+        app.world_mut().spawn(SpineBundle {
+            skeleton: skeleton_handle.clone().into(),
+            ..Default::default()
+        });
+    }
+
+    let started = Instant::now();
+    while app.world_mut().query::<&Spine>().iter(app.world()).count() < SKELETONS {
+        assert!(
+            started.elapsed() < Duration::from_secs(30),
+            "skeletons failed to load"
+        );
+        app.update();
+    }
+
+    let none = measure(&mut app, SpineDrawer::None);
+    for drawer in [SpineDrawer::Combined, SpineDrawer::Separated] {
+        let stats = measure(&mut app, drawer);
+        let allocated_bytes = stats.allocated_bytes - none.allocated_bytes;
+        let mesh_bytes = mesh_bytes(&app) as f64;
+        println!(
+            "{:?}: {:.0} allocations ({:.1} KiB, {:.1} KiB of mesh data) and {:.2?} per frame for {} skeletons",
+            drawer,
+            stats.allocations - none.allocations,
+            allocated_bytes / 1024.,
+            mesh_bytes / 1024.,
+            stats.time.saturating_sub(none.time),
+            SKELETONS,
+        );
+        // building new meshes every frame allocates at least all of their data
+        assert!(
+            allocated_bytes < mesh_bytes,
+            "{drawer:?} mesh updates allocated more than the mesh data they wrote",
+        );
+    }
+}
+
+/// The size of the indices and vertex attributes of all meshes.
+fn mesh_bytes(app: &App) -> usize {
+    app.world()
+        .resource::<Assets<Mesh>>()
+        .iter()
+        .map(|(_, mesh)| {
+            let indices = match mesh.indices() {
+                Some(Indices::U16(indices)) => indices.len() * 2,
+                Some(Indices::U32(indices)) => indices.len() * 4,
+                None => 0,
+            };
+            indices
+                + mesh
+                    .attributes()
+                    .map(|(_, values)| values.get_bytes().len())
+                    .sum::<usize>()
+        })
+        .sum()
+}
+
+fn measure(app: &mut App, drawer: SpineDrawer) -> FrameStats {
+    for mut settings in app
+        .world_mut()
+        .query::<&mut SpineSettings>()
+        .iter_mut(app.world_mut())
+    {
+        settings.drawer = drawer;
+    }
+    // warm up, so that meshes and materials exist
+    for _ in 0..10 {
+        app.update();
+    }
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let started = Instant::now();
+    for _ in 0..FRAMES {
+        app.update();
+    }
+    FrameStats {
+        allocations: (ALLOCATIONS.load(Ordering::Relaxed) - allocations) as f64 / FRAMES as f64,
+        allocated_bytes: (ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes) as f64
+            / FRAMES as f64,
+        time: started.elapsed() / FRAMES as u32,
+    }
+}
+
+fn on_spawn(
+    mut spine_ready_event: EventReader<SpineReadyEvent>,
+    mut spine_query: Query<&mut Spine>,
+) {
+    for event in spine_ready_event.read() {
+        if let Ok(mut spine) = spine_query.get_mut(event.entity) {
+            let _ = spine
+                .animation_state
+                .set_animation_by_name(0, "portal", true);
+        }
+    }
+}
@@ -84,7 +84,7 @@ fn check(sh: &Shell, target: Target) -> anyhow::Result<()> {
 }
 
 fn example_check(sh: &Shell) -> anyhow::Result<()> {
-    cmd!(sh, "cargo check --examples").run()?;
+    cmd!(sh, "cargo check --examples --all-features").run()?;
     Ok(())
 }
 
@@ -1,14 +1,9 @@
 use bevy::{
-    ecs::system::StaticSystemParam,
     input::mouse::MouseMotion,
     prelude::*,
     window::{CursorGrabMode, PrimaryWindow},
 };
-use bevy_spine::{
-    materials::{SpineMaterial, SpineMaterialInfo, SpineMaterialPlugin, SpineSettingsQuery},
-    prelude::*,
-    SpineMeshType,
-};
+use bevy_spine::{prelude::*, SpineLighting, SpineMeshType};
 
 #[derive(Component)]
 pub struct Orbit {
@@ -27,11 +22,7 @@ impl Default for Orbit {
 
 fn main() {
     App::new()
-        .add_plugins((
-            DefaultPlugins,
-            SpinePlugin,
-            SpineMaterialPlugin::<Spine3DMaterial>::default(),
-        ))
+        .add_plugins((DefaultPlugins, SpinePlugin))
         .add_systems(Startup, setup)
         .add_systems(Update, (on_spawn.in_set(SpineSet::OnReady), controls))
         .run();
@@ -77,8 +68,11 @@ fn setup(
         skeleton: skeleton_handle.clone().into(),
         transform: Transform::from_xyz(0., 0., 0.).with_scale(Vec3::ONE * 0.005),
         settings: SpineSettings {
-            default_materials: false,
             mesh_type: SpineMeshType::Mesh3D,
+            lighting: SpineLighting {
+                lit: true,
+                ..Default::default()
+            },
             ..Default::default()
         },
         ..Default::default()
@@ -130,40 +124,3 @@ fn controls(
         orbit_transform.look_at(Vec3::new(0., 1.5, 0.), Vec3::Y);
     }
 }
-
-#[derive(Component)]
-pub struct Spine3DMaterial;
-
-impl SpineMaterial for Spine3DMaterial {
-    type MeshMaterial = MeshMaterial3d<StandardMaterial>;
-    type Material = StandardMaterial;
-    type Params<'w, 's> = SpineSettingsQuery<'w, 's>;
-
-    fn update(
-        material: Option<Self::Material>,
-        entity: Entity,
-        renderable_data: SpineMaterialInfo,
-        params: &StaticSystemParam<Self::Params<'_, '_>>,
-    ) -> Option<Self::Material> {
-        let spine_settings = params
-            .spine_settings_query
-            .get(entity)
-            .copied()
-            .unwrap_or(SpineSettings::default());
-        if spine_settings.mesh_type == SpineMeshType::Mesh3D {
-            let mut material = material.unwrap_or_else(|| Self::Material {
-                unlit: true,
-                alpha_mode: if renderable_data.premultiplied_alpha {
-                    AlphaMode::Premultiplied
-                } else {
-                    AlphaMode::Blend
-                },
-                ..Self::Material::default()
-            });
-            material.base_color_texture = Some(renderable_data.texture);
-            Some(material)
-        } else {
-            None
-        }
-    }
-}
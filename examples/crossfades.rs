@@ -34,6 +34,7 @@ fn setup(
     let mut crossfades = Crossfades::new();
     crossfades.add("idle", "walk", 0.5);
     crossfades.add("walk", "idle", 0.5);
+    crossfades.set_default_mix(0.2);
 
     commands.spawn(SpineBundle {
         skeleton: skeleton_handle.clone().into(),
@@ -114,7 +114,6 @@ impl Material2d for MyMaterial {
     ) -> Result<(), SpecializedMeshPipelineError> {
         let vertex_attributes = vec![
             Mesh::ATTRIBUTE_POSITION.at_shader_location(0),
-            Mesh::ATTRIBUTE_NORMAL.at_shader_location(1),
             Mesh::ATTRIBUTE_UV_0.at_shader_location(2),
             Mesh::ATTRIBUTE_COLOR.at_shader_location(4),
             DARK_COLOR_ATTRIBUTE.at_shader_location(DARK_COLOR_SHADER_POSITION as u32),
@@ -1,18 +1,19 @@
 use bevy::prelude::*;
 use bevy_spine::{
-    SkeletonController, SkeletonData, Spine, SpineBundle, SpineEvent, SpinePlugin, SpineReadyEvent,
-    SpineSet,
+    events::{FromSpineEvent, SpineEventAppExt, SpineEventInfo},
+    SkeletonController, SkeletonData, Spine, SpineBundle, SpinePlugin, SpineReadyEvent, SpineSet,
 };
 
 fn main() {
     App::new()
         .add_plugins((DefaultPlugins, SpinePlugin))
+        .add_spine_event::<FootstepEvent>("footstep")
         .add_systems(Startup, setup)
         .add_systems(
             Update,
             (
                 on_spawn.in_set(SpineSet::OnReady),
-                on_spine_event.in_set(SpineSet::OnEvent),
+                on_footstep_event.in_set(SpineSet::OnEvent),
                 footstep_update,
             ),
         )
@@ -53,16 +54,30 @@ fn on_spawn(
     }
 }
 
-fn on_spine_event(mut spine_events: EventReader<SpineEvent>, mut commands: Commands) {
-    for event in spine_events.read() {
-        if let SpineEvent::Event { name, .. } = event {
-            commands
-                .spawn((Text2d(name.to_string()), Transform::from_xyz(0., -200., 1.)))
-                .insert(Footstep);
+#[derive(Event, Clone)]
+struct FootstepEvent {
+    name: String,
+}
+
+impl FromSpineEvent for FootstepEvent {
+    fn from_spine_event(info: SpineEventInfo) -> Self {
+        Self {
+            name: info.name.to_owned(),
         }
     }
 }
 
+fn on_footstep_event(mut footstep_events: EventReader<FootstepEvent>, mut commands: Commands) {
+    for event in footstep_events.read() {
+        commands
+            .spawn((
+                Text2d(event.name.clone()),
+                Transform::from_xyz(0., -200., 1.),
+            ))
+            .insert(Footstep);
+    }
+}
+
 #[derive(Component)]
 struct Footstep;
 
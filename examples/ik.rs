@@ -1,7 +1,7 @@
 use bevy::{prelude::*, window::PrimaryWindow};
 use bevy_spine::{
-    SkeletonController, SkeletonData, Spine, SpineBone, SpineBundle, SpinePlugin, SpineReadyEvent,
-    SpineSet, SpineSync, SpineSyncSet,
+    SkeletonController, SkeletonData, Spine, SpineBundle, SpineIkTarget, SpineIkTargets,
+    SpinePlugin, SpineReadyEvent, SpineSet,
 };
 
 #[derive(Component)]
@@ -15,7 +15,7 @@ fn main() {
             Update,
             (
                 on_spawn.in_set(SpineSet::OnReady),
-                ik.in_set(SpineSyncSet::DuringSync),
+                move_crosshair.before(SpineSet::OnReady),
             ),
         )
         .run();
@@ -34,20 +34,20 @@ fn setup(
     );
     let skeleton_handle = skeletons.add(skeleton);
 
-    commands.spawn((
-        SpineBundle {
-            transform: Transform::from_xyz(-200., -200., 0.).with_scale(Vec3::splat(0.5)),
-            skeleton: skeleton_handle.clone().into(),
-            ..Default::default()
-        },
-        SpineSync,
-    ));
+    commands.spawn(SpineBundle {
+        transform: Transform::from_xyz(-200., -200., 0.).with_scale(Vec3::splat(0.5)),
+        skeleton: skeleton_handle.clone().into(),
+        ..Default::default()
+    });
+
+    commands.spawn((Crosshair, Transform::default()));
 }
 
 fn on_spawn(
     mut spine_ready_event: EventReader<SpineReadyEvent>,
     mut spine_query: Query<&mut Spine>,
     mut commands: Commands,
+    crosshair: Single<Entity, With<Crosshair>>,
 ) {
     for event in spine_ready_event.read() {
         if let Ok(mut spine) = spine_query.get_mut(event.entity) {
@@ -60,27 +60,22 @@ fn on_spawn(
             let _ = animation_state.set_animation_by_name(0, "run", true);
             let _ = animation_state.set_animation_by_name(1, "aim", true);
             let _ = animation_state.set_animation_by_name(2, "shoot", true);
-            if let Some(mut crosshair_entity) = event
-                .bones
-                .get("crosshair")
-                .and_then(|crosshair_entity| commands.get_entity(*crosshair_entity))
-            {
-                crosshair_entity.insert(Crosshair);
-            }
+            commands
+                .entity(event.entity)
+                .insert(SpineIkTargets::from(SpineIkTarget::new(
+                    "aim-ik", *crosshair,
+                )));
         }
     }
 }
 
-fn ik(
-    mut crosshair_query: Query<(&mut Transform, &SpineBone), With<Crosshair>>,
-    window_query: Query<&Window, With<PrimaryWindow>>,
-    camera_query: Query<(Entity, &Camera)>,
-    global_transform_query: Query<&GlobalTransform>,
+fn move_crosshair(
+    mut crosshair_transform: Single<&mut Transform, With<Crosshair>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    camera: Single<(&Camera, &GlobalTransform)>,
 ) {
-    let (camera_entity, camera) = camera_query.single();
-    let camera_global_transform = global_transform_query.get(camera_entity).unwrap();
-    let window = window_query.single();
-    let cursor_position = window
+    let (camera, camera_global_transform) = *camera;
+    if let Some(cursor_position) = window
         .cursor_position()
         .and_then(|cursor| {
             camera
@@ -88,14 +83,7 @@ fn ik(
                 .ok()
         })
         .map(|ray| ray.origin.truncate())
-        .unwrap_or(Vec2::ZERO);
-
-    if let Ok((mut crosshair_transform, crosshair_bone)) = crosshair_query.get_single_mut() {
-        let parent_global_transform = global_transform_query
-            .get(crosshair_bone.parent.as_ref().unwrap().entity)
-            .unwrap();
-        crosshair_transform.translation = (parent_global_transform.compute_matrix().inverse()
-            * Vec4::new(cursor_position.x, cursor_position.y, 0., 1.))
-        .truncate();
+    {
+        crosshair_transform.translation = cursor_position.extend(0.);
     }
 }
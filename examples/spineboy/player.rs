@@ -128,7 +128,10 @@ fn player_spine_events(
     mut spine_query: Query<(&mut Spine, &mut Player)>,
 ) {
     for event in spine_events.read() {
-        if let SpineEvent::Complete { entity, animation } = event {
+        if let SpineEvent::Complete {
+            entity, animation, ..
+        } = event
+        {
             if let Ok((mut spine, mut player)) = spine_query.get_mut(*entity) {
                 let Spine(controller) = spine.as_mut();
                 if animation == "portal" {
@@ -8,12 +8,18 @@ use bevy::{
 use rusty_spine::SpineError;
 use thiserror::Error;
 
+use crate::Crossfades;
+
 #[derive(Debug, Error)]
 pub enum SpineLoaderError {
     #[error("Could load file: {0}")]
     Io(#[from] std::io::Error),
     #[error("Spine error: {0}")]
     Spine(#[from] SpineError),
+    #[error("Could not parse file: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    #[error("Sprite sheets require `Assets<TextureAtlasLayout>`, add Bevy's `SpritePlugin`")]
+    MissingTextureAtlasLayouts,
 }
 
 /// Bevy asset for [`rusty_spine::Atlas`], loaded from `.atlas` files.
@@ -124,6 +130,53 @@ impl AssetLoader for SkeletonBinaryLoader {
     }
 }
 
+/// Bevy asset for [`Crossfades`], loaded from `.crossfades.ron` files.
+///
+/// Applied to every instance of a skeleton, see [`SkeletonData::with_crossfades`].
+///
+/// ```ron
+/// (
+///     default_mix: 0.1,
+///     mixes: [
+///         (from: "walk", to: "run", duration: 0.2),
+///         (from: "run", to: "walk", duration: 0.2),
+///         (from: "*", to: "jump", duration: 0.05),
+///     ],
+/// )
+/// ```
+#[derive(Asset, Debug, TypePath)]
+pub struct CrossfadesAsset {
+    pub crossfades: Crossfades,
+}
+
+#[derive(Default)]
+pub(crate) struct CrossfadesLoader;
+
+impl AssetLoader for CrossfadesLoader {
+    type Asset = CrossfadesAsset;
+    type Settings = ();
+    type Error = SpineLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(CrossfadesAsset {
+            crossfades: ron::Options::default()
+                .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+                .from_bytes(&bytes)?,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["crossfades.ron"]
+    }
+}
+
 /// Bevy asset for [`rusty_spine::SkeletonData`], loaded asynchronously from [`Atlas`] and a
 /// skeleton (either [`SkeletonJson`] or [`SkeletonBinary`]).
 ///
@@ -134,6 +187,7 @@ pub struct SkeletonData {
     pub kind: SkeletonDataKind,
     pub status: SkeletonDataStatus,
     pub premultiplied_alpha: bool,
+    pub crossfades: Option<Handle<CrossfadesAsset>>,
 }
 
 #[derive(Debug)]
@@ -184,6 +238,7 @@ impl SkeletonData {
             kind: SkeletonDataKind::JsonFile(json),
             status: SkeletonDataStatus::Loading,
             premultiplied_alpha: false,
+            crossfades: None,
         }
     }
 
@@ -221,6 +276,30 @@ impl SkeletonData {
             kind: SkeletonDataKind::BinaryFile(binary),
             status: SkeletonDataStatus::Loading,
             premultiplied_alpha: false,
+            crossfades: None,
+        }
+    }
+
+    /// Apply crossfades from a [`CrossfadesAsset`] to every instance of this skeleton.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spine::prelude::*;
+    /// # fn doc(asset_server: Res<AssetServer>) {
+    /// let skeleton = SkeletonData::new_from_json(
+    ///     asset_server.load("./skeleton.json"),
+    ///     asset_server.load("./skeleton.atlas"),
+    /// )
+    /// .with_crossfades(asset_server.load("./skeleton.crossfades.ron"));
+    /// # }
+    /// ```
+    ///
+    /// The asset may be modified or reloaded at any time to update live skeletons. Any
+    /// [`Crossfades`] component on an entity takes priority over the asset.
+    pub fn with_crossfades(self, crossfades: Handle<CrossfadesAsset>) -> Self {
+        Self {
+            crossfades: Some(crossfades),
+            ..self
         }
     }
 
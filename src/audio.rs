@@ -0,0 +1,129 @@
+//! Automatic playback of Spine event audio. Requires the `audio` feature.
+//!
+//! See [`SpineAudioPlugin`]. Bevy's audio format features (such as `vorbis` or `wav`) must be
+//! enabled for the formats used by the skeleton.
+
+use bevy::{
+    audio::{PlaybackMode, Volume},
+    prelude::*,
+};
+
+use crate::{SkeletonData, SkeletonDataHandle, SkeletonDataKind, SpineEvent, SpineSystem};
+
+/// Plays the audio attached to Spine events with [`bevy_audio`](`bevy::audio`).
+///
+/// When a [`SpineEvent::Event`] fires with a non-empty `audio_path`, the audio file is loaded
+/// relative to the skeleton's asset directory (the directory of the `.json` or `.skel` file) and
+/// played with the event's `volume`.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{audio::SpineAudioPlugin, SpinePlugin};
+/// # fn doc() {
+/// App::new()
+///     .add_plugins((DefaultPlugins, SpinePlugin, SpineAudioPlugin))
+///     // ...
+///     .run();
+/// # }
+/// ```
+///
+/// Audio is played globally by default. To position it at the emitting entity, add a
+/// [`SpineAudioSettings`] component with `spatial: true`, and a [`SpatialListener`] to the scene.
+///
+/// The event's `balance` is not applied, since Bevy's [`PlaybackSettings`] have no stereo panning.
+/// It is still available in [`SpineEvent::Event`] for custom audio playback.
+pub struct SpineAudioPlugin;
+
+impl Plugin for SpineAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, spine_play_audio.after(SpineSystem::UpdateAnimation));
+    }
+}
+
+/// Settings for audio played by [`SpineAudioPlugin`] for this Spine.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct SpineAudioSettings {
+    /// Indicates if audio should be positioned at this entity (default: `false`).
+    pub spatial: bool,
+    /// Multiplied with the volume of each Spine event (default: `1.0`).
+    pub volume: f32,
+    /// Indicates if audio should be played at all (default: `true`).
+    pub enabled: bool,
+}
+
+impl Default for SpineAudioSettings {
+    fn default() -> Self {
+        Self {
+            spatial: false,
+            volume: 1.,
+            enabled: true,
+        }
+    }
+}
+
+fn spine_play_audio(
+    mut spine_events: EventReader<SpineEvent>,
+    mut commands: Commands,
+    spine_query: Query<(&SkeletonDataHandle, Option<&SpineAudioSettings>)>,
+    skeleton_data_assets: Res<Assets<SkeletonData>>,
+    asset_server: Res<AssetServer>,
+) {
+    for spine_event in spine_events.read() {
+        let SpineEvent::Event {
+            entity,
+            audio_path,
+            volume,
+            ..
+        } = spine_event
+        else {
+            continue;
+        };
+        if audio_path.is_empty() {
+            continue;
+        }
+        let Ok((skeleton_data_handle, audio_settings)) = spine_query.get(*entity) else {
+            continue;
+        };
+        let audio_settings = audio_settings.copied().unwrap_or_default();
+        if !audio_settings.enabled {
+            continue;
+        }
+        let Some(skeleton_data_asset) = skeleton_data_assets.get(&skeleton_data_handle.0) else {
+            continue;
+        };
+        let skeleton_path = match &skeleton_data_asset.kind {
+            SkeletonDataKind::JsonFile(handle) => handle.path(),
+            SkeletonDataKind::BinaryFile(handle) => handle.path(),
+        };
+        let audio_asset_path = match skeleton_path {
+            Some(skeleton_path) => match skeleton_path.resolve_embed(audio_path) {
+                Ok(audio_asset_path) => audio_asset_path,
+                Err(err) => {
+                    warn!("Invalid Spine audio path {:?}: {}", audio_path, err);
+                    continue;
+                }
+            },
+            None => audio_path.clone().into(),
+        };
+        let settings = PlaybackSettings {
+            mode: PlaybackMode::Despawn,
+            volume: Volume::new(volume * audio_settings.volume),
+            ..Default::default()
+        };
+        let audio_player = AudioPlayer::new(asset_server.load(audio_asset_path));
+        if audio_settings.spatial {
+            if let Some(mut entity_commands) = commands.get_entity(*entity) {
+                entity_commands.with_children(|parent| {
+                    parent.spawn((
+                        Name::new("spine_audio"),
+                        audio_player,
+                        settings.with_spatial(true),
+                        Transform::default(),
+                    ));
+                });
+            }
+        } else {
+            commands.spawn((Name::new("spine_audio"), audio_player, settings));
+        }
+    }
+}
@@ -0,0 +1,408 @@
+//! Command line tools for bevy_spine.
+//!
+//! ```text
+//! cargo install bevy_spine --features bake
+//! bevy_spine bake hero.json --atlas hero.atlas --anim run --fps 30 --scale 0.5
+//! ```
+//!
+//! `bake` renders Spine animations into a sprite sheet on the CPU (no GPU required), writing a
+//! PNG and a `.spritesheet.ron` file with the frame layout and animation timings, which can be
+//! loaded as a [`SpineSpriteSheet`](`bevy_spine::SpineSpriteSheet`).
+//!
+//! Skeletons are read from Spine's `.json` or `.skel` exports along with their `.atlas`.
+
+use std::{
+    collections::BTreeMap,
+    error::Error,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use bevy::{
+    asset::{LoadState, UntypedAssetId},
+    image::{CompressedImageFormats, ImageLoader},
+    math::Affine2,
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{Extent3d, TextureDimension, TextureFormat},
+    },
+};
+use bevy_spine::{
+    prelude::*,
+    raster::{SpineRaster, SpineRasterTexture, SpineRasterTextures},
+    textures::SpineTextureCreateEvent,
+    SkeletonDataKind, SkeletonDataStatus, SpineDrawer, SpineMixBlend, SpineSpriteSheetAnimation,
+    SpineSpriteSheetFile, SpineSpriteSheetFrame,
+};
+
+const USAGE: &str = "\
+Usage: bevy_spine bake <SKELETON> [OPTIONS]
+
+Bakes Spine animations into a sprite sheet.
+
+Arguments:
+  <SKELETON>            The skeleton to bake (.json or .skel, other formats such as .ron are not
+                        supported)
+
+Options:
+  --atlas <PATH>        The skeleton's atlas [default: <SKELETON>.atlas]
+  --anim <NAME>         An animation to bake, may be repeated [default: all animations]
+  --skin <NAME>         The skin to bake [default: the default skin]
+  --fps <FPS>           Frames sampled per second [default: 30]
+  --scale <SCALE>       Pixels per skeleton unit [default: 1]
+  --padding <PIXELS>    Empty pixels around each frame [default: 1]
+  --max-width <PIXELS>  Maximum width of the sheet [default: 4096]
+  --out <PATH>          Output path, without extension [default: <SKELETON> without extension]";
+
+/// Gives up if the skeleton and its atlas pages haven't loaded after this long.
+const LOAD_TIMEOUT: Duration = Duration::from_secs(30);
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let result = match args.first().map(String::as_str) {
+        Some("bake") => BakeOptions::parse(&args[1..]).and_then(|options| bake(&options)),
+        _ => Err(USAGE.into()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("{error}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+struct BakeOptions {
+    skeleton: PathBuf,
+    atlas: PathBuf,
+    animations: Vec<String>,
+    skin: Option<String>,
+    fps: f32,
+    scale: f32,
+    padding: u32,
+    max_width: u32,
+    out: PathBuf,
+}
+
+impl BakeOptions {
+    fn parse(args: &[String]) -> Result<Self, Box<dyn Error>> {
+        let mut skeleton = None;
+        let mut atlas = None;
+        let mut animations = vec![];
+        let mut skin = None;
+        let mut fps = 30.;
+        let mut scale = 1.;
+        let mut padding = 1;
+        let mut max_width = 4096;
+        let mut out = None;
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .cloned()
+                    .ok_or_else(|| format!("Missing value for {arg}"))
+            };
+            match arg.as_str() {
+                "--atlas" => atlas = Some(PathBuf::from(value()?)),
+                "--anim" => animations.push(value()?),
+                "--skin" => skin = Some(value()?),
+                "--fps" => fps = value()?.parse()?,
+                "--scale" => scale = value()?.parse()?,
+                "--padding" => padding = value()?.parse()?,
+                "--max-width" => max_width = value()?.parse()?,
+                "--out" => out = Some(PathBuf::from(value()?)),
+                "-h" | "--help" => return Err(USAGE.into()),
+                _ if skeleton.is_none() && !arg.starts_with('-') => {
+                    skeleton = Some(PathBuf::from(arg))
+                }
+                _ => return Err(format!("Unexpected argument: {arg}\n\n{USAGE}").into()),
+            }
+        }
+        let Some(skeleton) = skeleton else {
+            return Err(format!("Missing skeleton\n\n{USAGE}").into());
+        };
+        if !matches!(
+            skeleton
+                .extension()
+                .and_then(|extension| extension.to_str()),
+            Some("json" | "skel")
+        ) {
+            return Err(format!(
+                "Unsupported skeleton {}, expected a .json or .skel file",
+                skeleton.display()
+            )
+            .into());
+        }
+        if !(fps > 0. && scale > 0.) {
+            return Err("--fps and --scale must be positive".into());
+        }
+        Ok(Self {
+            atlas: atlas.unwrap_or_else(|| skeleton.with_extension("atlas")),
+            out: out.unwrap_or_else(|| skeleton.with_extension("")),
+            skeleton,
+            animations,
+            skin,
+            fps,
+            scale,
+            padding,
+            max_width,
+        })
+    }
+}
+
+/// Atlas pages loaded by `SpinePlugin`.
+#[derive(Default, Resource)]
+struct AtlasPages(Vec<SpineTextureCreateEvent>);
+
+fn collect_atlas_pages(
+    mut spine_texture_create_events: EventReader<SpineTextureCreateEvent>,
+    mut atlas_pages: ResMut<AtlasPages>,
+) {
+    atlas_pages
+        .0
+        .extend(spine_texture_create_events.read().cloned());
+}
+
+fn bake(options: &BakeOptions) -> Result<(), Box<dyn Error>> {
+    let skeleton_path = options.skeleton.canonicalize()?;
+    let atlas_path = options.atlas.canonicalize()?;
+    let root = skeleton_path
+        .ancestors()
+        .find(|ancestor| atlas_path.starts_with(ancestor))
+        .unwrap_or(Path::new(""))
+        .to_path_buf();
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin {
+            file_path: root.to_string_lossy().into_owned(),
+            ..Default::default()
+        },
+        TransformPlugin,
+        HierarchyPlugin,
+    ))
+    .init_asset::<Mesh>()
+    .init_asset::<Image>()
+    .init_asset::<Shader>()
+    .register_asset_loader(ImageLoader::new(CompressedImageFormats::NONE))
+    .add_plugins(SpinePlugin)
+    .init_resource::<AtlasPages>()
+    .add_systems(Update, collect_atlas_pages.after(SpineSystem::Load));
+
+    let skeleton_handle = {
+        let asset_server = app.world().resource::<AssetServer>();
+        let atlas = asset_server.load(atlas_path.strip_prefix(&root)?.to_path_buf());
+        let skeleton = skeleton_path.strip_prefix(&root)?.to_path_buf();
+        let skeleton = match skeleton
+            .extension()
+            .and_then(|extension| extension.to_str())
+        {
+            Some("skel") => SkeletonData::new_from_binary(asset_server.load(skeleton), atlas),
+            _ => SkeletonData::new_from_json(asset_server.load(skeleton), atlas),
+        };
+        app.world_mut()
+            .resource_mut::<Assets<SkeletonData>>()
+            .add(skeleton)
+    };
+    app.world_mut().spawn(SpineBundle {
+        skeleton: skeleton_handle.clone().into(),
+        settings: SpineSettings {
+            drawer: SpineDrawer::None,
+            ..Default::default()
+        },
+        ..Default::default()
+    });
+
+    let started = Instant::now();
+    loop {
+        app.update();
+        let spawned = app
+            .world_mut()
+            .query::<&Spine>()
+            .iter(app.world())
+            .next()
+            .is_some();
+        let world = app.world();
+        let asset_server = world.resource::<AssetServer>();
+        let failed = |id: UntypedAssetId| {
+            matches!(asset_server.get_load_state(id), Some(LoadState::Failed(_)))
+        };
+        if let Some(skeleton_data) = world
+            .resource::<Assets<SkeletonData>>()
+            .get(&skeleton_handle)
+        {
+            let skeleton_id = match &skeleton_data.kind {
+                SkeletonDataKind::JsonFile(handle) => handle.id().untyped(),
+                SkeletonDataKind::BinaryFile(handle) => handle.id().untyped(),
+            };
+            if matches!(skeleton_data.status, SkeletonDataStatus::Failed)
+                || failed(skeleton_id)
+                || failed(skeleton_data.atlas_handle.id().untyped())
+            {
+                return Err("Failed to load skeleton".into());
+            }
+        }
+        let mut pages_loaded = true;
+        for page in world.resource::<AtlasPages>().0.iter() {
+            if failed(page.handle.id().untyped()) {
+                return Err(format!("Failed to load {}", page.path).into());
+            }
+            pages_loaded &= asset_server.is_loaded_with_dependencies(page.handle.id());
+        }
+        if spawned && pages_loaded {
+            break;
+        }
+        if started.elapsed() > LOAD_TIMEOUT {
+            return Err("Timed out loading skeleton".into());
+        }
+    }
+    // lets SpinePlugin convert premultiplied alpha pages, as it does for the GPU
+    app.update();
+
+    let mut textures = SpineRasterTextures::default();
+    let images = app.world().resource::<Assets<Image>>();
+    for page in app.world().resource::<AtlasPages>().0.iter() {
+        let texture = images
+            .get(&page.handle)
+            .and_then(SpineRasterTexture::from_image)
+            .ok_or_else(|| format!("Unsupported image format: {}", page.path))?;
+        textures.insert_page(&page.path, texture.with_filter(page.config.mag_filter));
+    }
+
+    let world = app.world_mut();
+    let mut spine = world.query::<&mut Spine>().single_mut(world);
+    if let Some(skin) = &options.skin {
+        spine
+            .skeleton
+            .set_skin_by_name(skin)
+            .map_err(|error| format!("Failed to set skin {skin}: {error}"))?;
+    }
+    let skeleton_data = spine.skeleton.data();
+    let animations = if options.animations.is_empty() {
+        skeleton_data
+            .animations()
+            .map(|animation| (animation.name().to_owned(), animation.duration()))
+            .collect::<Vec<_>>()
+    } else {
+        options
+            .animations
+            .iter()
+            .map(|name| {
+                skeleton_data
+                    .animations()
+                    .find(|animation| animation.name() == name.as_str())
+                    .map(|animation| (name.clone(), animation.duration()))
+                    .ok_or_else(|| format!("Animation not found: {name}"))
+            })
+            .collect::<Result<Vec<_>, _>>()?
+    };
+
+    // sample every frame first, so that all frames can share the same size and origin
+    let mut frames = vec![];
+    let mut sheet_animations = BTreeMap::new();
+    for (name, duration) in animations {
+        let frame_count = ((duration * options.fps).ceil() as usize).max(1);
+        sheet_animations.insert(
+            name.clone(),
+            SpineSpriteSheetAnimation {
+                first: frames.len(),
+                frames: frame_count,
+                fps: options.fps,
+                duration,
+            },
+        );
+        for frame in 0..frame_count {
+            spine.skeleton.set_to_setup_pose();
+            spine
+                .pose_at(&name, frame as f32 / options.fps, SpineMixBlend::Setup)
+                .map_err(|error| format!("Failed to pose {name}: {error}"))?;
+            frames.push(spine.renderables());
+        }
+    }
+    let bounds = frames
+        .iter()
+        .filter_map(|renderables| SpineRaster::bounds(renderables))
+        .reduce(|a, b| a.union(b))
+        .ok_or("Nothing to bake, the skeleton has no visible attachments")?;
+
+    let padding = options.padding as f32;
+    let frame_size =
+        ((bounds.size() * options.scale).ceil().as_uvec2() + options.padding * 2).max(UVec2::ONE);
+    let origin = Vec2::new(-bounds.min.x, bounds.max.y) * options.scale + padding;
+    let transform =
+        Affine2::from_translation(origin) * Affine2::from_scale(Vec2::new(1., -1.) * options.scale);
+    let columns = (options.max_width / frame_size.x)
+        .max(1)
+        .min(frames.len() as u32);
+    let rows = (frames.len() as u32).div_ceil(columns);
+    let size = frame_size * UVec2::new(columns, rows);
+
+    let mut data = vec![0; size.x as usize * size.y as usize * 4];
+    let mut sheet_frames = vec![];
+    for (index, renderables) in frames.iter().enumerate() {
+        let position = UVec2::new(index as u32 % columns, index as u32 / columns) * frame_size;
+        let mut raster = SpineRaster::new(frame_size.x, frame_size.y).with_transform(transform);
+        raster.draw(renderables, &textures);
+        let pixels = raster.to_unpremultiplied_rgba8();
+        let row_length = frame_size.x as usize * 4;
+        for (y, row) in pixels.chunks_exact(row_length).enumerate() {
+            let start = ((position.y as usize + y) * size.x as usize + position.x as usize) * 4;
+            data[start..start + row_length].copy_from_slice(row);
+        }
+        sheet_frames.push(SpineSpriteSheetFrame {
+            x: position.x,
+            y: position.y,
+        });
+    }
+
+    let file_name = options
+        .out
+        .file_name()
+        .ok_or("Invalid output path")?
+        .to_string_lossy()
+        .into_owned();
+    let image_path = options.out.with_file_name(format!("{file_name}.png"));
+    let sheet_path = options
+        .out
+        .with_file_name(format!("{file_name}.spritesheet.ron"));
+    if let Some(parent) = options.out.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    Image::new(
+        Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    )
+    .try_into_dynamic()?
+    .save(&image_path)?;
+    let sheet = SpineSpriteSheetFile {
+        image: format!("{file_name}.png"),
+        size: size.into(),
+        frame_size: frame_size.into(),
+        origin: origin.into(),
+        frames: sheet_frames,
+        animations: sheet_animations,
+    };
+    std::fs::write(
+        &sheet_path,
+        ron::ser::to_string_pretty(&sheet, ron::ser::PrettyConfig::default())?,
+    )?;
+    println!(
+        "Baked {} frames ({}x{} each) into {} and {}",
+        frames.len(),
+        frame_size.x,
+        frame_size.y,
+        image_path.display(),
+        sheet_path.display(),
+    );
+    Ok(())
+}
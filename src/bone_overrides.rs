@@ -0,0 +1,189 @@
+//! Procedural bone adjustments blended on top of animations.
+//!
+//! See [`SpineBoneOverrides`].
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use rusty_spine::{Bone, Physics};
+
+use crate::Spine;
+
+/// How a [`SpineBoneOverride`] combines with the animated pose.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum SpineBoneOverrideMode {
+    /// Add the override's translation and rotation to the animated pose, and multiply its scale.
+    #[default]
+    Additive,
+    /// Blend from the animated pose towards the override.
+    Replace,
+}
+
+/// The space a [`SpineBoneOverride`]'s translation and rotation are expressed in.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Default, Debug, PartialEq)]
+pub enum SpineBoneOverrideSpace {
+    /// Relative to the bone's parent, like the bone's own local transform.
+    #[default]
+    Local,
+    /// Skeleton space, which is the local space of the [`Spine`] entity. Scale is always local.
+    World,
+}
+
+/// A procedural transform for a single bone, see [`SpineBoneOverrides`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct SpineBoneOverride {
+    pub translation: Vec2,
+    /// Counter clockwise rotation in degrees.
+    pub rotation: f32,
+    pub scale: Vec2,
+    /// How much of the override is applied, from `0.` to `1.` (default: `1.`).
+    pub weight: f32,
+    pub mode: SpineBoneOverrideMode,
+    pub space: SpineBoneOverrideSpace,
+}
+
+impl Default for SpineBoneOverride {
+    fn default() -> Self {
+        Self {
+            translation: Vec2::ZERO,
+            rotation: 0.,
+            scale: Vec2::ONE,
+            weight: 1.,
+            mode: SpineBoneOverrideMode::Additive,
+            space: SpineBoneOverrideSpace::Local,
+        }
+    }
+}
+
+/// Blends procedural transforms on top of a [`Spine`]'s animated bones, keyed by bone name.
+///
+/// Overrides are applied every frame after animations, but before constraints and world
+/// transforms are updated, so constraints (such as IK) still act on the adjusted pose. This is
+/// useful for effects like head tracking or recoil which should layer on top of authored
+/// animation rather than replace it, unlike writing bone transforms during
+/// [`SpineSyncSet::DuringSync`](`crate::SpineSyncSet::DuringSync`).
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpineBoneOverride, SpineBoneOverrides};
+/// fn recoil(mut overrides_query: Query<&mut SpineBoneOverrides>, time: Res<Time>) {
+///     for mut overrides in overrides_query.iter_mut() {
+///         let recoil = overrides.0.entry("rear-upper-arm".to_owned()).or_default();
+///         recoil.rotation = (recoil.rotation - 60. * time.delta_secs()).max(0.);
+///     }
+/// }
+///
+/// # fn doc(mut commands: Commands, spine_entity: Entity) {
+/// commands
+///     .entity(spine_entity)
+///     .insert(SpineBoneOverrides::default().with(
+///         "rear-upper-arm",
+///         SpineBoneOverride {
+///             rotation: 20.,
+///             ..Default::default()
+///         },
+///     ));
+/// # }
+/// ```
+///
+/// Bones missing from the skeleton are ignored.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct SpineBoneOverrides(pub HashMap<String, SpineBoneOverride>);
+
+impl SpineBoneOverrides {
+    pub fn with(mut self, bone: &str, bone_override: SpineBoneOverride) -> Self {
+        self.0.insert(bone.to_owned(), bone_override);
+        self
+    }
+}
+
+pub(crate) fn spine_update_bone_overrides(
+    mut spine_query: Query<(&mut Spine, &SpineBoneOverrides)>,
+) {
+    for (mut spine, bone_overrides) in spine_query.iter_mut() {
+        let skeleton = &mut spine.skeleton;
+        if bone_overrides
+            .0
+            .values()
+            .any(|bone_override| bone_override.space == SpineBoneOverrideSpace::World)
+        {
+            // world space overrides need the parent bones in their animated pose
+            skeleton.update_world_transform(Physics::None);
+        }
+        let (skeleton_x, skeleton_y) = (skeleton.x(), skeleton.y());
+        let (skeleton_scale_x, skeleton_scale_y) = (skeleton.scale_x(), skeleton.scale_y());
+        for (name, bone_override) in bone_overrides.0.iter() {
+            let Some(mut bone) = skeleton.find_bone_mut(name) else {
+                continue;
+            };
+            let weight = bone_override.weight.clamp(0., 1.);
+            if weight == 0. {
+                continue;
+            }
+            let (translation, rotation) = match bone_override.space {
+                SpineBoneOverrideSpace::Local => {
+                    (bone_override.translation, bone_override.rotation)
+                }
+                SpineBoneOverrideSpace::World => {
+                    let parent = bone.parent();
+                    let world_to_local = |x: f32, y: f32| match &parent {
+                        Some(parent) => Vec2::from(parent.world_to_local(x, y)),
+                        None => Vec2::new(
+                            (x - skeleton_x) / skeleton_scale_x,
+                            (y - skeleton_y) / skeleton_scale_y,
+                        ),
+                    };
+                    let world_to_local_rotation = |rotation: f32| match &parent {
+                        Some(parent) => parent.world_to_local_rotation(rotation),
+                        None => rotation,
+                    };
+                    let translation = bone_override.translation;
+                    match bone_override.mode {
+                        SpineBoneOverrideMode::Additive => (
+                            world_to_local(translation.x, translation.y) - world_to_local(0., 0.),
+                            world_to_local_rotation(bone_override.rotation)
+                                - world_to_local_rotation(0.),
+                        ),
+                        SpineBoneOverrideMode::Replace => (
+                            world_to_local(translation.x, translation.y),
+                            world_to_local_rotation(bone_override.rotation),
+                        ),
+                    }
+                }
+            };
+            apply_bone_override(&mut bone, bone_override, translation, rotation, weight);
+        }
+    }
+}
+
+fn apply_bone_override(
+    bone: &mut Bone,
+    bone_override: &SpineBoneOverride,
+    translation: Vec2,
+    rotation: f32,
+    weight: f32,
+) {
+    let scale = bone_override.scale;
+    match bone_override.mode {
+        SpineBoneOverrideMode::Additive => {
+            bone.set_x(bone.x() + translation.x * weight);
+            bone.set_y(bone.y() + translation.y * weight);
+            bone.set_rotation(bone.rotation() + rotation * weight);
+            bone.set_scale_x(bone.scale_x() * (1. + (scale.x - 1.) * weight));
+            bone.set_scale_y(bone.scale_y() * (1. + (scale.y - 1.) * weight));
+        }
+        SpineBoneOverrideMode::Replace => {
+            bone.set_x(bone.x() + (translation.x - bone.x()) * weight);
+            bone.set_y(bone.y() + (translation.y - bone.y()) * weight);
+            // blend along the shortest arc
+            let rotation_delta = (rotation - bone.rotation() + 180.).rem_euclid(360.) - 180.;
+            bone.set_rotation(bone.rotation() + rotation_delta * weight);
+            bone.set_scale_x(bone.scale_x() + (scale.x - bone.scale_x()) * weight);
+            bone.set_scale_y(bone.scale_y() + (scale.y - bone.scale_y()) * weight);
+        }
+    }
+}
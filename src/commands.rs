@@ -0,0 +1,205 @@
+//! [`EntityCommands`] extensions for driving Spine animations.
+//!
+//! See [`SpineEntityCommandsExt`].
+
+use bevy::prelude::*;
+
+use crate::Spine;
+
+/// A buffered operation on a [`Spine`], see [`SpineEntityCommandsExt`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpineCommand {
+    /// See [`SpineEntityCommandsExt::spine_play`].
+    Play {
+        track: usize,
+        animation: String,
+        looping: bool,
+    },
+    /// See [`SpineEntityCommandsExt::spine_queue`].
+    Queue {
+        track: usize,
+        animation: String,
+        looping: bool,
+        delay: f32,
+    },
+    /// See [`SpineEntityCommandsExt::spine_clear_track`].
+    ClearTrack { track: usize },
+    /// See [`SpineEntityCommandsExt::spine_set_skin`].
+    SetSkin { skin: String },
+}
+
+impl SpineCommand {
+    /// Apply this command to a live [`Spine`].
+    pub fn apply(&self, spine: &mut Spine) {
+        match self {
+            SpineCommand::Play {
+                track,
+                animation,
+                looping,
+            } => {
+                if let Err(err) = spine
+                    .animation_state
+                    .set_animation_by_name(*track, animation, *looping)
+                {
+                    warn!("Failed to play Spine animation: {}", err);
+                }
+            }
+            SpineCommand::Queue {
+                track,
+                animation,
+                looping,
+                delay,
+            } => {
+                if let Err(err) = spine
+                    .animation_state
+                    .add_animation_by_name(*track, animation, *looping, *delay)
+                {
+                    warn!("Failed to queue Spine animation: {}", err);
+                }
+            }
+            SpineCommand::ClearTrack { track } => {
+                spine.animation_state.clear_track(*track);
+            }
+            SpineCommand::SetSkin { skin } => {
+                if let Err(err) = spine.skeleton.set_skin_by_name(skin) {
+                    warn!("Failed to set Spine skin: {}", err);
+                } else {
+                    spine.skeleton.set_slots_to_setup_pose();
+                }
+            }
+        }
+    }
+}
+
+/// [`SpineCommand`]s waiting for a [`Spine`] to finish loading.
+///
+/// Applied in [`SpineSystem::Ready`](`crate::SpineSystem::Ready`), before any
+/// [`SpineSet::OnReady`](`crate::SpineSet::OnReady`) systems.
+#[derive(Component, Default, Debug, Clone)]
+pub struct SpinePendingCommands(pub Vec<SpineCommand>);
+
+/// Extension trait for driving Spine animations with [`Commands`].
+///
+/// Commands are applied immediately if the [`Spine`] is loaded, otherwise they are buffered in
+/// [`SpinePendingCommands`] and applied as soon as the skeleton is ready, so a skeleton can be
+/// spawned and animated in the same system.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// use bevy_spine::prelude::*;
+///
+/// fn spawn(mut commands: Commands) {
+///     commands
+///         .spawn(SpineBundle {
+///             // ..
+///             ..Default::default()
+///         })
+///         .spine_set_skin("default")
+///         .spine_play(0, "idle", true);
+/// }
+///
+/// fn jump(mut commands: Commands, player: Single<Entity, With<Spine>>) {
+///     commands
+///         .entity(*player)
+///         .spine_play(0, "jump", false)
+///         .spine_queue(0, "idle", true, 0.);
+/// }
+/// ```
+pub trait SpineEntityCommandsExt {
+    /// Set the current animation on a track, see
+    /// [`AnimationState::set_animation_by_name`](`rusty_spine::AnimationState::set_animation_by_name`).
+    fn spine_play(&mut self, track: usize, animation: &str, looping: bool) -> &mut Self;
+
+    /// Queue an animation on a track, see
+    /// [`AnimationState::add_animation_by_name`](`rusty_spine::AnimationState::add_animation_by_name`).
+    fn spine_queue(
+        &mut self,
+        track: usize,
+        animation: &str,
+        looping: bool,
+        delay: f32,
+    ) -> &mut Self;
+
+    /// Clear a track, see
+    /// [`AnimationState::clear_track`](`rusty_spine::AnimationState::clear_track`).
+    fn spine_clear_track(&mut self, track: usize) -> &mut Self;
+
+    /// Set the skeleton's skin and reset slots to their setup pose, see
+    /// [`Skeleton::set_skin_by_name`](`rusty_spine::Skeleton::set_skin_by_name`).
+    fn spine_set_skin(&mut self, skin: &str) -> &mut Self;
+
+    /// Apply a [`SpineCommand`], or buffer it until the [`Spine`] is ready.
+    fn spine_command(&mut self, command: SpineCommand) -> &mut Self;
+}
+
+impl SpineEntityCommandsExt for EntityCommands<'_> {
+    fn spine_play(&mut self, track: usize, animation: &str, looping: bool) -> &mut Self {
+        self.spine_command(SpineCommand::Play {
+            track,
+            animation: animation.to_owned(),
+            looping,
+        })
+    }
+
+    fn spine_queue(
+        &mut self,
+        track: usize,
+        animation: &str,
+        looping: bool,
+        delay: f32,
+    ) -> &mut Self {
+        self.spine_command(SpineCommand::Queue {
+            track,
+            animation: animation.to_owned(),
+            looping,
+            delay,
+        })
+    }
+
+    fn spine_clear_track(&mut self, track: usize) -> &mut Self {
+        self.spine_command(SpineCommand::ClearTrack { track })
+    }
+
+    fn spine_set_skin(&mut self, skin: &str) -> &mut Self {
+        self.spine_command(SpineCommand::SetSkin {
+            skin: skin.to_owned(),
+        })
+    }
+
+    fn spine_command(&mut self, command: SpineCommand) -> &mut Self {
+        self.queue(move |mut entity: EntityWorldMut| {
+            if let Some(mut spine) = entity.get_mut::<Spine>() {
+                command.apply(&mut spine);
+            } else if let Some(mut pending_commands) = entity.get_mut::<SpinePendingCommands>() {
+                pending_commands.0.push(command);
+            } else {
+                entity.insert(SpinePendingCommands(vec![command]));
+            }
+        })
+    }
+}
+
+pub(crate) fn spine_apply_pending_commands(
+    mut spine_query: Query<(Entity, &mut Spine, &mut SpinePendingCommands)>,
+    mut commands: Commands,
+) {
+    for (spine_entity, mut spine, mut pending_commands) in spine_query.iter_mut() {
+        if pending_commands.0.is_empty() {
+            continue;
+        }
+        for command in std::mem::take(&mut pending_commands.0) {
+            command.apply(&mut spine);
+        }
+        // commands buffered before this runs stay in the component and are applied next frame
+        commands
+            .entity(spine_entity)
+            .queue(|mut entity: EntityWorldMut| {
+                if entity
+                    .get::<SpinePendingCommands>()
+                    .is_some_and(|pending_commands| pending_commands.0.is_empty())
+                {
+                    entity.remove::<SpinePendingCommands>();
+                }
+            });
+    }
+}
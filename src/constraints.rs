@@ -0,0 +1,173 @@
+//! Components for controlling Spine constraints by name.
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+use crate::Spine;
+
+/// Mix values for a transform constraint, see [`SpineTransformConstraints`].
+///
+/// Each value is a percentage (`0.` to `1.`) controlling the mix between the constrained and
+/// unconstrained bone property.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct SpineTransformConstraintMix {
+    pub rotate: f32,
+    pub x: f32,
+    pub y: f32,
+    pub scale_x: f32,
+    pub scale_y: f32,
+    pub shear_y: f32,
+}
+
+impl Default for SpineTransformConstraintMix {
+    fn default() -> Self {
+        Self {
+            rotate: 1.,
+            x: 1.,
+            y: 1.,
+            scale_x: 1.,
+            scale_y: 1.,
+            shear_y: 1.,
+        }
+    }
+}
+
+/// Mix and positioning values for a path constraint, see [`SpinePathConstraints`].
+#[derive(Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct SpinePathConstraintMix {
+    /// A percentage (`0.` to `1.`) controlling the mix between the constrained and unconstrained
+    /// rotation.
+    pub rotate: f32,
+    /// A percentage (`0.` to `1.`) controlling the mix between the constrained and unconstrained
+    /// x translation.
+    pub x: f32,
+    /// A percentage (`0.` to `1.`) controlling the mix between the constrained and unconstrained
+    /// y translation.
+    pub y: f32,
+    /// The position along the path, or `None` to keep the animated position.
+    pub position: Option<f32>,
+    /// The spacing between bones, or `None` to keep the animated spacing.
+    pub spacing: Option<f32>,
+}
+
+impl Default for SpinePathConstraintMix {
+    fn default() -> Self {
+        Self {
+            rotate: 1.,
+            x: 1.,
+            y: 1.,
+            position: None,
+            spacing: None,
+        }
+    }
+}
+
+/// Overrides the mixes of a [`Spine`]'s transform constraints, keyed by constraint name.
+///
+/// Applied every frame after animations, replacing any animated values. Constraints missing from
+/// the skeleton are ignored.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpineTransformConstraints};
+/// fn aim(mut constraints_query: Query<&mut SpineTransformConstraints>, time: Res<Time>) {
+///     for mut constraints in constraints_query.iter_mut() {
+///         let mix = constraints.0.entry("aim-torso".to_owned()).or_default();
+///         mix.rotate = (mix.rotate + time.delta_secs()).min(1.);
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct SpineTransformConstraints(pub HashMap<String, SpineTransformConstraintMix>);
+
+impl SpineTransformConstraints {
+    pub fn with(mut self, constraint: &str, mix: SpineTransformConstraintMix) -> Self {
+        self.0.insert(constraint.to_owned(), mix);
+        self
+    }
+}
+
+/// Overrides the mixes of a [`Spine`]'s path constraints, keyed by constraint name.
+///
+/// Applied every frame after animations, replacing any animated values. Constraints missing from
+/// the skeleton are ignored.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct SpinePathConstraints(pub HashMap<String, SpinePathConstraintMix>);
+
+impl SpinePathConstraints {
+    pub fn with(mut self, constraint: &str, mix: SpinePathConstraintMix) -> Self {
+        self.0.insert(constraint.to_owned(), mix);
+        self
+    }
+}
+
+/// Overrides the mixes of a [`Spine`]'s physics constraints, keyed by constraint name.
+///
+/// Applied every frame after animations, replacing any animated values. Constraints missing from
+/// the skeleton are ignored.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct SpinePhysicsConstraints(pub HashMap<String, f32>);
+
+impl SpinePhysicsConstraints {
+    pub fn with(mut self, constraint: &str, mix: f32) -> Self {
+        self.0.insert(constraint.to_owned(), mix);
+        self
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(crate) fn spine_update_constraints(
+    mut spine_query: Query<
+        (
+            &mut Spine,
+            Option<&SpineTransformConstraints>,
+            Option<&SpinePathConstraints>,
+            Option<&SpinePhysicsConstraints>,
+        ),
+        Or<(
+            With<SpineTransformConstraints>,
+            With<SpinePathConstraints>,
+            With<SpinePhysicsConstraints>,
+        )>,
+    >,
+) {
+    for (mut spine, transform_constraints, path_constraints, physics_constraints) in
+        spine_query.iter_mut()
+    {
+        let skeleton = &mut spine.skeleton;
+        for (name, mix) in transform_constraints.iter().flat_map(|c| c.0.iter()) {
+            if let Some(mut constraint) = skeleton.find_transform_constraint_mut(name) {
+                constraint.set_mix_rotate(mix.rotate);
+                constraint.set_mix_x(mix.x);
+                constraint.set_mix_y(mix.y);
+                constraint.set_mix_scale_x(mix.scale_x);
+                constraint.set_mix_scale_y(mix.scale_y);
+                constraint.set_shear_y(mix.shear_y);
+            }
+        }
+        for (name, mix) in path_constraints.iter().flat_map(|c| c.0.iter()) {
+            if let Some(mut constraint) = skeleton.find_path_constraint_mut(name) {
+                constraint.set_mix_rotate(mix.rotate);
+                constraint.set_mix_x(mix.x);
+                constraint.set_mix_y(mix.y);
+                if let Some(position) = mix.position {
+                    constraint.set_position(position);
+                }
+                if let Some(spacing) = mix.spacing {
+                    constraint.set_spacing(spacing);
+                }
+            }
+        }
+        for (name, mix) in physics_constraints.iter().flat_map(|c| c.0.iter()) {
+            if let Some(mut constraint) = skeleton.find_physics_constraint_mut(name) {
+                constraint.set_mix(*mix);
+            }
+        }
+    }
+}
@@ -1,7 +1,8 @@
 use std::collections::HashMap;
 
 use bevy::prelude::*;
-use rusty_spine::AnimationStateData;
+use rusty_spine::{AnimationStateData, SkeletonData};
+use serde::Deserialize;
 
 /// Crossfade data to apply to [`rusty_spine::AnimationStateData`]. Allows automated crossfading
 /// between animations.
@@ -20,6 +21,12 @@ use rusty_spine::AnimationStateData;
 /// // Apply in the other direction too
 /// crossfades.add("run", "walk", 0.2);
 ///
+/// // Blend from any animation into jump
+/// crossfades.add(Crossfades::ANY, "jump", 0.1);
+///
+/// // Blend everything else for 0.05 secs
+/// crossfades.set_default_mix(0.05);
+///
 /// commands.spawn(SpineBundle {
 ///     // ...
 ///     crossfades,
@@ -27,27 +34,134 @@ use rusty_spine::AnimationStateData;
 /// });
 /// # }
 /// ```
-
-#[derive(Component, Default, Debug, Clone)]
+///
+/// Changes to this component on a live [`Spine`](`crate::Spine`) are applied to its
+/// [`AnimationStateData`] before the next animation update.
+///
+/// Crossfades shared by every instance of a skeleton can also be loaded from a
+/// [`CrossfadesAsset`](`crate::CrossfadesAsset`) (see
+/// [`SkeletonData::with_crossfades`](`crate::SkeletonData::with_crossfades`)). Entries on this
+/// component take priority over entries in the asset.
+#[derive(Component, Default, Debug, Clone, PartialEq, Deserialize)]
+#[serde(from = "CrossfadesFile")]
 pub struct Crossfades {
     mix_durations: HashMap<(String, String), f32>,
+    default_mix: Option<f32>,
 }
 
 impl Crossfades {
+    /// Wildcard animation name, matching any animation.
+    pub const ANY: &'static str = "*";
+
     pub fn new() -> Self {
         Self {
             mix_durations: HashMap::new(),
+            default_mix: None,
         }
     }
 
+    /// Add a crossfade between two animations. Either name may be [`Crossfades::ANY`].
     pub fn add(&mut self, from: &str, to: &str, mix_duration: f32) {
         self.mix_durations
             .insert((from.to_owned(), to.to_owned()), mix_duration);
     }
 
-    pub(crate) fn apply(&self, animation_state_data: &mut AnimationStateData) {
-        for ((from, to), mix_duration) in self.mix_durations.iter() {
-            animation_state_data.set_mix_by_name(from, to, *mix_duration);
+    /// Remove a crossfade previously added with [`Crossfades::add`], returning its duration.
+    pub fn remove(&mut self, from: &str, to: &str) -> Option<f32> {
+        self.mix_durations.remove(&(from.to_owned(), to.to_owned()))
+    }
+
+    /// Remove all crossfades, including the default mix.
+    pub fn clear(&mut self) {
+        self.mix_durations.clear();
+        self.default_mix = None;
+    }
+
+    /// The mix duration used for any pair of animations without a crossfade.
+    pub fn default_mix(&self) -> Option<f32> {
+        self.default_mix
+    }
+
+    /// Set the default mix, see [`Crossfades::default_mix`].
+    pub fn set_default_mix(&mut self, default_mix: f32) {
+        self.default_mix = Some(default_mix);
+    }
+
+    /// Unset the default mix, see [`Crossfades::default_mix`].
+    pub fn clear_default_mix(&mut self) {
+        self.default_mix = None;
+    }
+
+    /// Get the mix duration between two animations, if any crossfade matches.
+    ///
+    /// An exact match takes priority over `(ANY, to)`, then `(from, ANY)`, then `(ANY, ANY)`. The
+    /// default mix is not considered.
+    pub fn mix(&self, from: &str, to: &str) -> Option<f32> {
+        [
+            (from, to),
+            (Self::ANY, to),
+            (from, Self::ANY),
+            (Self::ANY, Self::ANY),
+        ]
+        .into_iter()
+        .find_map(|(from, to)| {
+            self.mix_durations
+                .get(&(from.to_owned(), to.to_owned()))
+                .copied()
+        })
+    }
+
+    /// Apply several layers of crossfades, where later layers take priority over earlier ones.
+    pub(crate) fn apply(
+        layers: &[&Crossfades],
+        animation_state_data: &mut AnimationStateData,
+        skeleton_data: &SkeletonData,
+    ) {
+        let default_mix = layers
+            .iter()
+            .rev()
+            .find_map(|crossfades| crossfades.default_mix)
+            .unwrap_or(0.);
+        animation_state_data.set_default_mix(default_mix);
+        for from in skeleton_data.animations() {
+            for to in skeleton_data.animations() {
+                let mix_duration = layers
+                    .iter()
+                    .rev()
+                    .find_map(|crossfades| crossfades.mix(from.name(), to.name()))
+                    .unwrap_or(default_mix);
+                // unset pairs resolve to the default mix, so only write the ones that differ
+                if animation_state_data.get_mix(&from, &to) != mix_duration {
+                    animation_state_data.set_mix(&from, &to, mix_duration);
+                }
+            }
+        }
+    }
+}
+
+/// The on-disk representation of [`Crossfades`], see [`CrossfadesAsset`](`crate::CrossfadesAsset`).
+#[derive(Deserialize)]
+struct CrossfadesFile {
+    #[serde(default)]
+    default_mix: Option<f32>,
+    #[serde(default)]
+    mixes: Vec<CrossfadesFileMix>,
+}
+
+#[derive(Deserialize)]
+struct CrossfadesFileMix {
+    from: String,
+    to: String,
+    duration: f32,
+}
+
+impl From<CrossfadesFile> for Crossfades {
+    fn from(file: CrossfadesFile) -> Self {
+        let mut crossfades = Crossfades::new();
+        crossfades.default_mix = file.default_mix;
+        for mix in file.mixes {
+            crossfades.add(&mix.from, &mix.to, mix.duration);
         }
+        crossfades
     }
 }
@@ -0,0 +1,301 @@
+//! Drawing Spine skeletons into renderables, and batching renderables into meshes.
+//!
+//! rusty_spine's drawers compute world vertices into a fixed buffer of 1000 floats, which
+//! overflows for mesh attachments with more than 500 vertices, so skeletons are drawn here with
+//! buffers sized for each attachment instead.
+
+use rusty_spine::{
+    c::{c_void, spMeshAttachment_updateRegion},
+    controller::{SkeletonController, SkeletonRenderable},
+    draw::{ColorSpace, CullDirection},
+    BlendMode, Color, RendererObject, Slot,
+};
+
+use crate::Spine;
+
+impl Spine {
+    /// Draws the skeleton into a [`SkeletonRenderable`] for each visible attachment, in draw
+    /// order.
+    ///
+    /// Use this instead of
+    /// [`SkeletonController::renderables`](`rusty_spine::controller::SkeletonController::renderables`),
+    /// which writes out of bounds for mesh attachments with more than 500 vertices.
+    pub fn renderables(&mut self) -> Vec<SkeletonRenderable> {
+        let mut buffers = DrawBuffers::default();
+        draw_renderables(&mut self.0, |_, _| false, &mut buffers);
+        buffers.renderables.truncate(buffers.renderable_count);
+        buffers.renderables
+    }
+}
+
+/// Buffers reused across skeletons and frames, so that drawing does not allocate once they are
+/// large enough.
+#[derive(Default)]
+pub(crate) struct DrawBuffers {
+    renderables: Vec<SkeletonRenderable>,
+    renderable_count: usize,
+    world_vertices: Vec<f32>,
+}
+
+// SAFETY: the renderer object pointers of the renderables are only dereferenced by the system
+// which drew them, while it has exclusive access to the skeleton owning them. Stale renderables
+// past `renderable_count` are overwritten before they are read again.
+unsafe impl Send for DrawBuffers {}
+unsafe impl Sync for DrawBuffers {}
+
+impl DrawBuffers {
+    /// The renderables drawn by the last call to [`draw_renderables`].
+    pub(crate) fn renderables(&self) -> &[SkeletonRenderable] {
+        &self.renderables[..self.renderable_count]
+    }
+}
+
+/// Draws the attachments of the skeleton like
+/// [`SimpleDrawer`](`rusty_spine::draw::SimpleDrawer`) into `buffers`, skipping slots for which
+/// `hidden` returns `true` as if they had no attachment. `hidden` is called with each slot's draw
+/// order index and the slot.
+///
+/// [`SkeletonRenderable::slot_index`] is the draw order index of the slot.
+pub(crate) fn draw_renderables(
+    controller: &mut SkeletonController,
+    hidden: impl Fn(usize, &Slot) -> bool,
+    buffers: &mut DrawBuffers,
+) {
+    let SkeletonController {
+        skeleton,
+        clipper,
+        settings,
+        ..
+    } = controller;
+    let DrawBuffers {
+        renderables,
+        renderable_count,
+        world_vertices,
+    } = buffers;
+    *renderable_count = 0;
+    for slot_index in 0..skeleton.slots_count() {
+        let Some(slot) = skeleton.draw_order_at_index(slot_index) else {
+            continue;
+        };
+        if !slot.bone().active() || hidden(slot_index, &slot) {
+            clipper.clip_end(&slot);
+            continue;
+        }
+
+        let mut color;
+        let attachment_renderer_object;
+        if let Some(mesh_attachment) = slot.attachment().and_then(|a| a.as_mesh()) {
+            color = mesh_attachment.color();
+            let world_vertices_length = mesh_attachment.world_vertices_length() as usize;
+            let vertex_count = world_vertices_length / 2;
+            world_vertices.resize(world_vertices.len().max(world_vertices_length), 0.);
+            let triangles = unsafe {
+                spMeshAttachment_updateRegion(mesh_attachment.c_ptr());
+                // the slot is the one the attachment was taken from
+                mesh_attachment.compute_world_vertices(
+                    &slot,
+                    0,
+                    world_vertices_length as i32,
+                    world_vertices,
+                    0,
+                    2,
+                );
+                std::slice::from_raw_parts(
+                    mesh_attachment.triangles(),
+                    mesh_attachment.triangles_count() as usize,
+                )
+            };
+            let attachment_uvs = mesh_attachment.uvs();
+            let renderable = next_renderable(renderables, *renderable_count);
+            renderable.vertices.extend(
+                world_vertices[..vertex_count * 2]
+                    .chunks_exact(2)
+                    .map(|vertex| [vertex[0], vertex[1]]),
+            );
+            // only uvs of vertices used by triangles are read, like `SimpleDrawer`
+            renderable.uvs.resize(vertex_count, [0., 0.]);
+            for &index in triangles {
+                let index = index as usize;
+                renderable.uvs[index] = unsafe {
+                    [
+                        *attachment_uvs.add(index * 2),
+                        *attachment_uvs.add(index * 2 + 1),
+                    ]
+                };
+            }
+            match settings.cull_direction {
+                CullDirection::Clockwise => renderable.indices.extend_from_slice(triangles),
+                CullDirection::CounterClockwise => renderable.indices.extend(
+                    triangles
+                        .chunks_exact(3)
+                        .flat_map(|triangle| [triangle[2], triangle[1], triangle[0]]),
+                ),
+            }
+            attachment_renderer_object =
+                unsafe { page_renderer_object(mesh_attachment.renderer_object()) };
+        } else if let Some(region_attachment) = slot.attachment().and_then(|a| a.as_region()) {
+            color = region_attachment.color();
+            world_vertices.resize(world_vertices.len().max(8), 0.);
+            unsafe {
+                // the slot is the one the attachment was taken from
+                region_attachment.compute_world_vertices(&slot, world_vertices, 0, 2);
+            }
+            let renderable = next_renderable(renderables, *renderable_count);
+            renderable.vertices.extend(
+                world_vertices[..8]
+                    .chunks_exact(2)
+                    .map(|vertex| [vertex[0], vertex[1]]),
+            );
+            renderable.uvs.extend(
+                region_attachment
+                    .uvs()
+                    .chunks_exact(2)
+                    .map(|uv| [uv[0], uv[1]]),
+            );
+            renderable
+                .indices
+                .extend_from_slice(match settings.cull_direction {
+                    CullDirection::Clockwise => &[0, 1, 2, 2, 3, 0],
+                    CullDirection::CounterClockwise => &[2, 1, 0, 0, 3, 2],
+                });
+            attachment_renderer_object =
+                unsafe { page_renderer_object(region_attachment.renderer_object()) };
+        } else {
+            match slot.attachment().and_then(|a| a.as_clipping()) {
+                Some(clipping_attachment) => clipper.clip_start(&slot, &clipping_attachment),
+                None => clipper.clip_end(&slot),
+            }
+            continue;
+        }
+        let renderable = &mut renderables[*renderable_count];
+        *renderable_count += 1;
+
+        if clipper.is_clipping() {
+            unsafe {
+                // the triangles index the vertices and uvs of this attachment
+                clipper.clip_triangles(
+                    &mut renderable.vertices,
+                    &mut renderable.indices,
+                    &mut renderable.uvs,
+                    2,
+                );
+                let clipper = &*clipper.c_ptr();
+                let clipped_vertices = &*clipper.clippedVertices;
+                let clipped_triangles = &*clipper.clippedTriangles;
+                let clipped_uvs = &*clipper.clippedUVs;
+                renderable.vertices.clear();
+                renderable
+                    .vertices
+                    .extend_from_slice(std::slice::from_raw_parts(
+                        clipped_vertices.items.cast::<[f32; 2]>(),
+                        clipped_vertices.size as usize / 2,
+                    ));
+                renderable.indices.clear();
+                renderable
+                    .indices
+                    .extend_from_slice(std::slice::from_raw_parts(
+                        clipped_triangles.items,
+                        clipped_triangles.size as usize,
+                    ));
+                renderable.uvs.clear();
+                renderable.uvs.extend_from_slice(std::slice::from_raw_parts(
+                    clipped_uvs.items.cast::<[f32; 2]>(),
+                    clipped_uvs.size as usize / 2,
+                ));
+            }
+        }
+
+        color *= slot.color() * skeleton.color();
+        let mut dark_color = slot.dark_color().unwrap_or_default();
+        if settings.premultiplied_alpha {
+            color.premultiply_alpha();
+            dark_color *= color.a;
+            dark_color.a = 1.;
+        } else {
+            dark_color.a = 0.;
+        }
+        if settings.color_space == ColorSpace::Linear {
+            color = color.nonlinear_to_linear();
+            dark_color = dark_color.nonlinear_to_linear();
+        }
+
+        renderable.slot_index = slot_index;
+        renderable.color = color;
+        renderable.dark_color = dark_color;
+        renderable.blend_mode = slot.data().blend_mode();
+        renderable.premultiplied_alpha = settings.premultiplied_alpha;
+        renderable.attachment_renderer_object = attachment_renderer_object;
+        clipper.clip_end(&slot);
+    }
+    clipper.clip_end2();
+}
+
+/// The next renderable to draw into, reusing the buffers of a previously drawn renderable if
+/// possible.
+fn next_renderable(
+    renderables: &mut Vec<SkeletonRenderable>,
+    renderable_count: usize,
+) -> &mut SkeletonRenderable {
+    if renderable_count == renderables.len() {
+        renderables.push(SkeletonRenderable {
+            slot_index: 0,
+            vertices: vec![],
+            uvs: vec![],
+            indices: vec![],
+            color: Color::default(),
+            dark_color: Color::default(),
+            blend_mode: BlendMode::Normal,
+            premultiplied_alpha: false,
+            attachment_renderer_object: None,
+        });
+    }
+    let renderable = &mut renderables[renderable_count];
+    renderable.vertices.clear();
+    renderable.uvs.clear();
+    renderable.indices.clear();
+    renderable
+}
+
+/// The renderer object of the atlas page of a mesh or region attachment, which
+/// [`SpinePlugin`](`crate::SpinePlugin`) sets to a [`SpineTexture`](`crate::textures::SpineTexture`).
+///
+/// # Safety
+///
+/// The attachment must have been created by the default attachment loader, or by
+/// [`SpineImageAttachment`](`crate::SpineImageAttachment`).
+unsafe fn page_renderer_object(mut renderer_object: RendererObject) -> Option<*const c_void> {
+    let region = renderer_object.get_atlas_region()?;
+    let page_renderer_object = (*region.page().c_ptr()).rendererObject;
+    (!page_renderer_object.is_null()).then_some(page_renderer_object.cast_const())
+}
+
+/// Splits renderables into batches of consecutive renderables which can be drawn as a single
+/// mesh, since they share a texture, blend mode and alpha mode. Renderables without any
+/// triangles never split batches.
+pub(crate) fn batch_renderables(renderables: &[SkeletonRenderable]) -> Vec<&[SkeletonRenderable]> {
+    let mut batches: Vec<(usize, usize)> = vec![];
+    let mut first: Option<&SkeletonRenderable> = None;
+    for (index, renderable) in renderables.iter().enumerate() {
+        if renderable.indices.is_empty() {
+            continue;
+        }
+        match (batches.last_mut(), first) {
+            (Some((_, end)), Some(first))
+                if first.blend_mode == renderable.blend_mode
+                    && first.premultiplied_alpha == renderable.premultiplied_alpha
+                    && first.attachment_renderer_object
+                        == renderable.attachment_renderer_object =>
+            {
+                *end = index + 1;
+            }
+            _ => {
+                batches.push((index, index + 1));
+                first = Some(renderable);
+            }
+        }
+    }
+    batches
+        .into_iter()
+        .map(|(start, end)| &renderables[start..end])
+        .collect()
+}
@@ -0,0 +1,136 @@
+//! Hit flash, outline and dissolve effects for the built-in materials.
+//!
+//! See [`SpineMaterialEffects`].
+
+use bevy::prelude::*;
+
+pub(crate) use uniform::SpineEffectsUniform;
+
+/// Effects applied by the built-in materials to every mesh of a [`Spine`](`crate::Spine`).
+///
+/// Effects are layered on top of the default materials, so they work with every Spine blend mode
+/// and with premultiplied alpha. Meshes of skeletons without this component skip the effects
+/// entirely.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpineMaterialEffects};
+/// fn hit_flash(mut effects_query: Query<&mut SpineMaterialEffects>, time: Res<Time>) {
+///     for mut effects in effects_query.iter_mut() {
+///         effects.flash_amount = (effects.flash_amount - time.delta_secs() * 4.).max(0.);
+///     }
+/// }
+/// ```
+///
+/// Outlines are drawn around each attachment, within the transparent padding of its atlas region,
+/// so attachments need enough padding for the outline width.
+#[derive(Component, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct SpineMaterialEffects {
+    /// The color to flash towards (default: white).
+    pub flash_color: Color,
+    /// How far the color is mixed towards `flash_color`, from `0.` to `1.` (default: `0.`).
+    pub flash_amount: f32,
+    /// The color of the outline (default: black).
+    pub outline_color: Color,
+    /// The width of the outline in texels, or `0.` to disable it (default: `0.`).
+    pub outline_width: f32,
+    /// A noise texture sampled by dissolve, using its red channel. Dissolve is disabled without it
+    /// (default: `None`).
+    pub dissolve_noise: Option<Handle<Image>>,
+    /// Texels where the noise is below this threshold are discarded, from `0.` (fully visible) to
+    /// `1.` (fully dissolved) (default: `0.`).
+    pub dissolve_threshold: f32,
+    /// The color of the dissolve edge (default: white).
+    pub dissolve_edge_color: Color,
+    /// The width of the dissolve edge, in noise values above the threshold (default: `0.`).
+    pub dissolve_edge_width: f32,
+}
+
+impl Default for SpineMaterialEffects {
+    fn default() -> Self {
+        Self {
+            flash_color: Color::WHITE,
+            flash_amount: 0.,
+            outline_color: Color::BLACK,
+            outline_width: 0.,
+            dissolve_noise: None,
+            dissolve_threshold: 0.,
+            dissolve_edge_color: Color::WHITE,
+            dissolve_edge_width: 0.,
+        }
+    }
+}
+
+impl SpineMaterialEffects {
+    pub fn with_flash(mut self, color: Color, amount: f32) -> Self {
+        self.flash_color = color;
+        self.flash_amount = amount;
+        self
+    }
+
+    pub fn with_outline(mut self, color: Color, width: f32) -> Self {
+        self.outline_color = color;
+        self.outline_width = width;
+        self
+    }
+
+    pub fn with_dissolve(mut self, noise: Handle<Image>, threshold: f32) -> Self {
+        self.dissolve_noise = Some(noise);
+        self.dissolve_threshold = threshold;
+        self
+    }
+
+    pub fn with_dissolve_edge(mut self, color: Color, width: f32) -> Self {
+        self.dissolve_edge_color = color;
+        self.dissolve_edge_width = width;
+        self
+    }
+}
+
+mod uniform {
+    // the field checks generated by `ShaderType` are never called for crate private types
+    #![allow(dead_code)]
+
+    use bevy::{prelude::*, render::render_resource::ShaderType};
+
+    use super::SpineMaterialEffects;
+
+    const FLAG_PREMULTIPLIED_ALPHA: u32 = 1;
+    const FLAG_DISSOLVE: u32 = 2;
+
+    /// [`SpineMaterialEffects`] as passed to the shaders.
+    #[derive(ShaderType, Default, Debug, Clone)]
+    pub(crate) struct SpineEffectsUniform {
+        flash_color: LinearRgba,
+        outline_color: LinearRgba,
+        dissolve_edge_color: LinearRgba,
+        flash_amount: f32,
+        outline_width: f32,
+        dissolve_threshold: f32,
+        dissolve_edge_width: f32,
+        flags: u32,
+    }
+
+    impl SpineEffectsUniform {
+        pub(crate) fn new(effects: &SpineMaterialEffects, premultiplied_alpha: bool) -> Self {
+            let mut flags = 0;
+            if premultiplied_alpha {
+                flags |= FLAG_PREMULTIPLIED_ALPHA;
+            }
+            if effects.dissolve_noise.is_some() {
+                flags |= FLAG_DISSOLVE;
+            }
+            Self {
+                flash_color: effects.flash_color.to_linear(),
+                outline_color: effects.outline_color.to_linear(),
+                dissolve_edge_color: effects.dissolve_edge_color.to_linear(),
+                flash_amount: effects.flash_amount,
+                outline_width: effects.outline_width,
+                dissolve_threshold: effects.dissolve_threshold,
+                dissolve_edge_width: effects.dissolve_edge_width,
+                flags,
+            }
+        }
+    }
+}
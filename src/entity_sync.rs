@@ -3,7 +3,7 @@ use std::{fmt::Debug, hash::Hash, marker::PhantomData};
 use bevy::prelude::*;
 use rusty_spine::Physics;
 
-use crate::{Spine, SpineBone, SpineSystem};
+use crate::{Spine, SpineBone, SpinePhysics, SpineSystem};
 
 /// See [`SpineSynchronizerPlugin`].
 pub trait SpineSynchronizer: Component + Clone + Eq + Debug + Hash {}
@@ -162,10 +162,10 @@ pub fn spine_sync_entities<S: SpineSynchronizer>(
 /// Synchronizes Spine skeleton bones to [`SpineBone`] transforms.
 pub fn spine_sync_bones<S: SpineSynchronizer>(
     mut bone_query: Query<(&mut Transform, &SpineBone)>,
-    mut spine_query: Query<&mut Spine, With<S>>,
+    mut spine_query: Query<(&mut Spine, Option<&SpinePhysics>), With<S>>,
 ) {
     for (bone_transform, bone) in bone_query.iter_mut() {
-        if let Ok(mut spine) = spine_query.get_mut(bone.spine_entity) {
+        if let Ok((mut spine, _)) = spine_query.get_mut(bone.spine_entity) {
             if let Some(mut bone) = bone.handle.get_mut(&mut spine.skeleton) {
                 bone.set_x(bone_transform.translation.x);
                 bone.set_y(bone_transform.translation.y);
@@ -176,8 +176,9 @@ pub fn spine_sync_bones<S: SpineSynchronizer>(
             }
         }
     }
-    for mut spine in spine_query.iter_mut() {
-        spine.0.skeleton.update_world_transform(Physics::Update);
+    for (mut spine, spine_physics) in spine_query.iter_mut() {
+        let physics = spine_physics.map_or(Physics::Update, SpinePhysics::physics);
+        spine.0.skeleton.update_world_transform(physics);
     }
 }
 
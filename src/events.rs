@@ -0,0 +1,203 @@
+//! Strongly-typed events mapped from Spine event names.
+//!
+//! See [`SpineEventAppExt::add_spine_event`].
+
+use std::{any::type_name, ffi::CStr, marker::PhantomData};
+
+use bevy::prelude::*;
+
+use crate::{SpineEvent, SpineSet, SpineSystem, SpineTrackEntryInfo};
+
+/// The payload of a [`SpineEvent::Event`], used to construct a [`FromSpineEvent`] type.
+#[derive(Debug, Clone, Copy)]
+pub struct SpineEventInfo<'a> {
+    /// The entity containing the [`Spine`](`crate::Spine`) which fired this event.
+    pub entity: Entity,
+    pub name: &'a str,
+    /// The animation time this event was keyed.
+    pub time: f32,
+    pub int: i32,
+    pub float: f32,
+    pub string: &'a str,
+    pub audio_path: &'a str,
+    pub volume: f32,
+    pub balance: f32,
+    /// The track entry playing the animation which fired this event.
+    pub entry: SpineTrackEntryInfo,
+}
+
+/// Trait for [`Event`]s which can be constructed from a Spine event. See
+/// [`SpineEventAppExt::add_spine_event`].
+pub trait FromSpineEvent: Event + Clone {
+    fn from_spine_event(info: SpineEventInfo) -> Self;
+}
+
+/// Extension trait for registering [`FromSpineEvent`] types on an [`App`].
+pub trait SpineEventAppExt {
+    /// Map a Spine event name to a strongly-typed [`Event`].
+    ///
+    /// Whenever a [`SpineEvent::Event`] with a matching name fires, `T` is both sent as a
+    /// buffered event (read with [`EventReader`]) and triggered for observers targeting the Spine
+    /// entity. Both happen before [`SpineSet::OnEvent`].
+    ///
+    /// A warning is logged when a skeleton loads without an event by this name.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// use bevy_spine::prelude::*;
+    ///
+    /// #[derive(Event, Clone)]
+    /// struct Footstep {
+    ///     entity: Entity,
+    ///     volume: f32,
+    /// }
+    ///
+    /// impl FromSpineEvent for Footstep {
+    ///     fn from_spine_event(info: SpineEventInfo) -> Self {
+    ///         Self {
+    ///             entity: info.entity,
+    ///             volume: info.volume,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # fn doc() {
+    /// fn main() {
+    ///     App::new()
+    ///         .add_plugins((DefaultPlugins, SpinePlugin))
+    ///         .add_spine_event::<Footstep>("footstep")
+    ///         .add_systems(Update, on_footstep.in_set(SpineSet::OnEvent))
+    ///         // ...
+    ///         .run();
+    /// }
+    /// # }
+    ///
+    /// fn on_footstep(mut footsteps: EventReader<Footstep>) {
+    ///     for footstep in footsteps.read() {
+    ///         println!("footstep from {:?}", footstep.entity);
+    ///     }
+    /// }
+    /// ```
+    fn add_spine_event<T: FromSpineEvent>(&mut self, name: &str) -> &mut Self;
+}
+
+impl SpineEventAppExt for App {
+    fn add_spine_event<T: FromSpineEvent>(&mut self, name: &str) -> &mut Self {
+        if !self.world().contains_resource::<SpineEventNames<T>>() {
+            self.add_event::<T>()
+                .init_resource::<SpineEventNames<T>>()
+                .add_systems(
+                    Update,
+                    spine_typed_events::<T>
+                        .after(SpineSystem::UpdateAnimation)
+                        .before(SpineSet::OnEvent),
+                );
+        }
+        self.world_mut()
+            .resource_mut::<SpineEventNames<T>>()
+            .names
+            .push(name.to_owned());
+        self.init_resource::<SpineEventRegistry>()
+            .world_mut()
+            .resource_mut::<SpineEventRegistry>()
+            .names
+            .push((name.to_owned(), type_name::<T>()));
+        self
+    }
+}
+
+/// Spine event names mapped to a single [`FromSpineEvent`] type.
+#[derive(Resource)]
+struct SpineEventNames<T: FromSpineEvent> {
+    names: Vec<String>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: FromSpineEvent> Default for SpineEventNames<T> {
+    fn default() -> Self {
+        Self {
+            names: vec![],
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// All registered Spine event names, used to validate skeletons as they load.
+#[derive(Resource, Default)]
+pub(crate) struct SpineEventRegistry {
+    names: Vec<(String, &'static str)>,
+}
+
+impl SpineEventRegistry {
+    pub(crate) fn warn_missing(&self, skeleton_data: &rusty_spine::SkeletonData, skeleton: &str) {
+        if self.names.is_empty() {
+            return;
+        }
+        let event_names = event_names(skeleton_data);
+        for (name, type_name) in self.names.iter() {
+            if !event_names.contains(name) {
+                warn!(
+                    "Spine event {:?} (mapped to {}) does not exist in skeleton {}",
+                    name, type_name, skeleton
+                );
+            }
+        }
+    }
+}
+
+fn event_names(skeleton_data: &rusty_spine::SkeletonData) -> Vec<String> {
+    // event data is not exposed by rusty_spine, so read it from the C struct directly
+    let c_skeleton_data = skeleton_data.c_ptr();
+    unsafe {
+        (0..(*c_skeleton_data).eventsCount as isize)
+            .map(|index| {
+                let c_event_data = *(*c_skeleton_data).events.offset(index);
+                CStr::from_ptr((*c_event_data).name)
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect()
+    }
+}
+
+fn spine_typed_events<T: FromSpineEvent>(
+    mut spine_events: EventReader<SpineEvent>,
+    mut typed_events: EventWriter<T>,
+    mut commands: Commands,
+    names: Res<SpineEventNames<T>>,
+) {
+    for spine_event in spine_events.read() {
+        let SpineEvent::Event {
+            entity,
+            name,
+            time,
+            int,
+            float,
+            string,
+            audio_path,
+            volume,
+            balance,
+            entry,
+        } = spine_event
+        else {
+            continue;
+        };
+        if !names.names.contains(name) {
+            continue;
+        }
+        let typed_event = T::from_spine_event(SpineEventInfo {
+            entity: *entity,
+            name,
+            time: *time,
+            int: *int,
+            float: *float,
+            string,
+            audio_path,
+            volume: *volume,
+            balance: *balance,
+            entry: *entry,
+        });
+        typed_events.send(typed_event.clone());
+        commands.trigger_targets(typed_event, *entity);
+    }
+}
@@ -0,0 +1,136 @@
+//! IK constraints driven by Bevy entities.
+//!
+//! See [`SpineIkTargets`].
+
+use bevy::prelude::*;
+use rusty_spine::Physics;
+
+use crate::Spine;
+
+/// Drives IK constraints of a [`Spine`] so that they reach towards other entities.
+///
+/// Every frame after animations and [`SpineBoneOverrides`](`crate::SpineBoneOverrides`) are
+/// applied, the [`GlobalTransform`] of each target entity is converted to skeleton space
+/// (accounting for the [`Spine`] entity's own transform, including flips) and the constraint's
+/// target bone is moved there, relative to its parent bone as posed this frame. The constraint's
+/// `mix`, `softness` and bend direction are overwritten as well, replacing any animated values.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpineIkTarget, SpineIkTargets};
+/// # fn doc(mut commands: Commands, spine_entity: Entity) {
+/// let crosshair = commands.spawn(Transform::from_xyz(100., 50., 0.)).id();
+/// let ground = commands.spawn(Transform::from_xyz(0., -100., 0.)).id();
+/// commands.entity(spine_entity).insert(SpineIkTargets::new([
+///     SpineIkTarget::new("aim-ik", crosshair),
+///     SpineIkTarget::new("front-leg-ik", ground).with_mix(0.5),
+/// ]));
+/// # }
+/// ```
+///
+/// Targets are applied in order, and any other IK constraints sharing the same target bone follow
+/// the entity too.
+#[derive(Component, Default, Debug, Clone, PartialEq)]
+pub struct SpineIkTargets(pub Vec<SpineIkTarget>);
+
+impl SpineIkTargets {
+    pub fn new(targets: impl IntoIterator<Item = SpineIkTarget>) -> Self {
+        Self(targets.into_iter().collect())
+    }
+
+    pub fn with(mut self, target: SpineIkTarget) -> Self {
+        self.0.push(target);
+        self
+    }
+}
+
+impl From<SpineIkTarget> for SpineIkTargets {
+    fn from(target: SpineIkTarget) -> Self {
+        Self(vec![target])
+    }
+}
+
+/// An IK constraint driven towards an entity, see [`SpineIkTargets`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpineIkTarget {
+    /// The name of the IK constraint.
+    pub constraint: String,
+    /// The entity to reach towards.
+    pub target: Entity,
+    /// How much the constraint affects its bones, from `0.` to `1.` (default: `1.`).
+    pub mix: f32,
+    /// For two bone IK, the distance from the maximum reach at which the bones start to bend
+    /// less (default: `0.`).
+    pub softness: f32,
+    /// For two bone IK, indicates if the bones bend in the positive rotation direction (default:
+    /// `true`).
+    pub bend_positive: bool,
+}
+
+impl SpineIkTarget {
+    pub fn new(constraint: &str, target: Entity) -> Self {
+        Self {
+            constraint: constraint.to_owned(),
+            target,
+            mix: 1.,
+            softness: 0.,
+            bend_positive: true,
+        }
+    }
+
+    pub fn with_mix(mut self, mix: f32) -> Self {
+        self.mix = mix;
+        self
+    }
+
+    pub fn with_softness(mut self, softness: f32) -> Self {
+        self.softness = softness;
+        self
+    }
+
+    pub fn with_bend_positive(mut self, bend_positive: bool) -> Self {
+        self.bend_positive = bend_positive;
+        self
+    }
+}
+
+pub(crate) fn spine_update_ik_targets(
+    mut spine_query: Query<(&mut Spine, &SpineIkTargets, &GlobalTransform)>,
+    global_transform_query: Query<&GlobalTransform>,
+) {
+    for (mut spine, ik_targets, spine_global_transform) in spine_query.iter_mut() {
+        if ik_targets.0.is_empty() {
+            continue;
+        }
+        let skeleton = &mut spine.skeleton;
+        // the target bones are positioned relative to their parents in their animated pose
+        skeleton.update_world_transform(Physics::None);
+        let (skeleton_x, skeleton_y) = (skeleton.x(), skeleton.y());
+        let (skeleton_scale_x, skeleton_scale_y) = (skeleton.scale_x(), skeleton.scale_y());
+        // skeleton space is the local space of the spine entity
+        let inverse = spine_global_transform.affine().inverse();
+        for ik_target in ik_targets.0.iter() {
+            let Ok(target_global_transform) = global_transform_query.get(ik_target.target) else {
+                continue;
+            };
+            let position = inverse.transform_point3(target_global_transform.translation());
+            let Some(mut ik_constraint) = skeleton.find_ik_constraint_mut(&ik_target.constraint)
+            else {
+                continue;
+            };
+            ik_constraint.set_mix(ik_target.mix);
+            ik_constraint.set_softness(ik_target.softness);
+            ik_constraint.set_bend_direction(if ik_target.bend_positive { 1 } else { -1 });
+            let mut target_bone = ik_constraint.target_mut();
+            let (x, y) = match target_bone.parent() {
+                Some(parent) => parent.world_to_local(position.x, position.y),
+                None => (
+                    (position.x - skeleton_x) / skeleton_scale_x,
+                    (position.y - skeleton_y) / skeleton_scale_y,
+                ),
+            };
+            target_bone.set_x(x);
+            target_bone.set_y(y);
+        }
+    }
+}
@@ -0,0 +1,242 @@
+//! Slot attachments drawing Bevy images.
+//!
+//! See [`SpineImageAttachment`] and [`SpineSlotImages`].
+
+use std::{collections::HashMap, ffi::CString, os::raw::c_char, ptr::addr_of_mut};
+
+use bevy::prelude::*;
+use rusty_spine::{
+    c::{
+        _spAttachmentVtable, _spRegionAttachment_dispose, spAtlasPage, spAtlasRegion, spAttachment,
+        spRegionAttachment, spRegionAttachment_create, spRegionAttachment_updateRegion,
+    },
+    c_interface::NewFromPtr,
+    Attachment,
+};
+
+use crate::{textures::SpineTexture, Spine};
+
+/// A region attachment drawing a Bevy [`Image`] instead of an atlas region, useful for user
+/// generated content such as custom emblems.
+///
+/// Most of the time, [`SpineSlotImages`] is the easiest way to show an image in a slot. For more
+/// control, [`SpineImageAttachment::create_attachment`] creates the attachment directly so it can
+/// be added to skins or slots with [`rusty_spine`].
+///
+/// The image is drawn with the same materials as the rest of the skeleton, so if the skeleton
+/// uses premultiplied alpha, the image should be premultiplied as well.
+#[derive(Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Default, Debug, PartialEq)]
+pub struct SpineImageAttachment {
+    pub image: Handle<Image>,
+    /// The size of the attachment in skeleton units.
+    pub size: Vec2,
+    /// The position of the attachment's center, relative to its bone.
+    pub offset: Vec2,
+    /// Counter clockwise rotation relative to its bone in degrees.
+    pub rotation: f32,
+}
+
+impl SpineImageAttachment {
+    pub fn new(image: Handle<Image>, size: Vec2) -> Self {
+        Self {
+            image,
+            size,
+            offset: Vec2::ZERO,
+            rotation: 0.,
+        }
+    }
+
+    pub fn with_offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    pub fn with_rotation(mut self, rotation: f32) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    /// Create a region attachment drawing this image.
+    ///
+    /// The attachment owns a strong handle to the image, which is released once the attachment is
+    /// no longer referenced by any skin or [`Attachment`].
+    #[must_use]
+    pub fn create_attachment(&self, name: &str) -> Attachment {
+        let c_name = CString::new(name).unwrap();
+        unsafe {
+            let region_attachment = create_image_region_attachment(
+                c_name.as_ptr(),
+                SpineTexture::Image(self.image.clone()),
+            );
+            (*region_attachment).x = self.offset.x;
+            (*region_attachment).y = self.offset.y;
+            (*region_attachment).rotation = self.rotation;
+            (*region_attachment).width = self.size.x;
+            (*region_attachment).height = self.size.y;
+            spRegionAttachment_updateRegion(region_attachment);
+            Attachment::new_from_ptr(region_attachment.cast())
+        }
+    }
+}
+
+/// The atlas region and page backing an image attachment, so that it renders like any other
+/// region attachment. Owned by the attachment and freed when it is disposed.
+#[repr(C)]
+struct ImageRegion {
+    region: spAtlasRegion,
+    page: spAtlasPage,
+    texture: SpineTexture,
+}
+
+unsafe fn create_image_region_attachment(
+    name: *const c_char,
+    texture: SpineTexture,
+) -> *mut spRegionAttachment {
+    let image_region = Box::into_raw(Box::new(ImageRegion {
+        region: std::mem::zeroed(),
+        page: std::mem::zeroed(),
+        texture,
+    }));
+    // a single region covering the whole image, sized relative to the attachment
+    let region = addr_of_mut!((*image_region).region);
+    (*region).super_0.u2 = 1.;
+    (*region).super_0.v2 = 1.;
+    (*region).super_0.width = 1;
+    (*region).super_0.height = 1;
+    (*region).super_0.originalWidth = 1;
+    (*region).super_0.originalHeight = 1;
+    (*region).page = addr_of_mut!((*image_region).page);
+    (*image_region).page.rendererObject = addr_of_mut!((*image_region).texture).cast();
+
+    let region_attachment = spRegionAttachment_create(name);
+    (*region_attachment).rendererObject = region.cast();
+    (*region_attachment).region = addr_of_mut!((*region).super_0);
+    // every attachment has its own vtable, so only this attachment is affected
+    let vtable = (*region_attachment)
+        .super_0
+        .vtable
+        .cast_mut()
+        .cast::<_spAttachmentVtable>();
+    (*vtable).dispose = Some(dispose_image_attachment);
+    (*vtable).copy = Some(copy_image_attachment);
+    region_attachment
+}
+
+unsafe extern "C" fn dispose_image_attachment(attachment: *mut spAttachment) {
+    let region_attachment = attachment.cast::<spRegionAttachment>();
+    drop(Box::from_raw(
+        (*region_attachment).rendererObject.cast::<ImageRegion>(),
+    ));
+    _spRegionAttachment_dispose(attachment);
+}
+
+unsafe extern "C" fn copy_image_attachment(attachment: *mut spAttachment) -> *mut spAttachment {
+    let region_attachment = attachment.cast::<spRegionAttachment>();
+    let image_region = (*region_attachment).rendererObject.cast::<ImageRegion>();
+    let copy = create_image_region_attachment((*attachment).name, (*image_region).texture.clone());
+    (*copy).x = (*region_attachment).x;
+    (*copy).y = (*region_attachment).y;
+    (*copy).scaleX = (*region_attachment).scaleX;
+    (*copy).scaleY = (*region_attachment).scaleY;
+    (*copy).rotation = (*region_attachment).rotation;
+    (*copy).width = (*region_attachment).width;
+    (*copy).height = (*region_attachment).height;
+    (*copy).color = (*region_attachment).color;
+    spRegionAttachment_updateRegion(copy);
+    copy.cast()
+}
+
+/// Shows Bevy images in the slots of a [`Spine`], keyed by slot name.
+///
+/// Every frame after animations are applied, each slot's attachment is replaced with a
+/// [`SpineImageAttachment`], overriding any attachment keys in the animations. Removing a slot
+/// from the map (or removing this component) restores the slot's setup pose attachment.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpineImageAttachment, SpineSlotImages};
+/// # fn doc(mut commands: Commands, asset_server: Res<AssetServer>, spine_entity: Entity) {
+/// let emblem: Handle<Image> = asset_server.load("emblem.png");
+/// commands.entity(spine_entity).insert(SpineSlotImages::default().with(
+///     "shield",
+///     SpineImageAttachment::new(emblem, Vec2::new(40., 40.)).with_rotation(-90.),
+/// ));
+/// # }
+/// ```
+///
+/// Slots missing from the skeleton are ignored.
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+#[require(SpineSlotImagesState)]
+pub struct SpineSlotImages(pub HashMap<String, SpineImageAttachment>);
+
+impl SpineSlotImages {
+    pub fn with(mut self, slot: &str, image: SpineImageAttachment) -> Self {
+        self.0.insert(slot.to_owned(), image);
+        self
+    }
+}
+
+/// The attachments created for [`SpineSlotImages`]. Kept in a separate component so that they
+/// outlive [`SpineSlotImages`] until the slots no longer point to them.
+#[derive(Component, Default, Debug)]
+pub(crate) struct SpineSlotImagesState {
+    attachments: HashMap<String, (SpineImageAttachment, Attachment)>,
+}
+
+pub(crate) fn spine_update_slot_images(
+    mut spine_query: Query<(
+        &mut Spine,
+        Option<&SpineSlotImages>,
+        &mut SpineSlotImagesState,
+    )>,
+) {
+    for (mut spine, slot_images, mut state) in spine_query.iter_mut() {
+        let skeleton = &mut spine.skeleton;
+        state.attachments.retain(|slot_name, (_, attachment)| {
+            if slot_images.is_some_and(|slot_images| slot_images.0.contains_key(slot_name)) {
+                return true;
+            }
+            // restore the setup attachment before the image attachment is disposed
+            let setup_attachment_name = skeleton.find_slot(slot_name).and_then(|slot| {
+                slot.data()
+                    .attachment_name()
+                    .map(|attachment_name| attachment_name.to_owned())
+            });
+            let setup_attachment = setup_attachment_name.and_then(|attachment_name| {
+                skeleton.get_attachment_for_slot_name(slot_name, &attachment_name)
+            });
+            if let Some(mut slot) = skeleton.find_slot_mut(slot_name) {
+                if slot
+                    .attachment()
+                    .is_some_and(|slot_attachment| slot_attachment.c_ptr() == attachment.c_ptr())
+                {
+                    unsafe {
+                        slot.set_attachment(setup_attachment);
+                    }
+                }
+            }
+            false
+        });
+        for (slot_name, image) in slot_images
+            .iter()
+            .flat_map(|slot_images| slot_images.0.iter())
+        {
+            let Some(mut slot) = skeleton.find_slot_mut(slot_name) else {
+                continue;
+            };
+            let attachment = match state.attachments.get(slot_name) {
+                Some((current, attachment)) if current == image => attachment.clone(),
+                _ => image.create_attachment(slot_name),
+            };
+            unsafe {
+                slot.set_attachment(Some(attachment.clone()));
+            }
+            // disposes any previous attachment, now that the slot no longer uses it
+            state
+                .attachments
+                .insert(slot_name.clone(), (image.clone(), attachment));
+        }
+    }
+}
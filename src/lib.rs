@@ -5,20 +5,32 @@
 use std::{
     collections::{HashMap, VecDeque},
     mem::take,
-    sync::{Arc, Mutex},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, LazyLock, Mutex,
+    },
 };
 
+#[cfg(feature = "3d")]
+use bevy::pbr::NotShadowCaster;
 use bevy::{
     asset::load_internal_binary_asset,
+    ecs::{component::ComponentId, world::DeferredWorld},
     image::{ImageAddressMode, ImageFilterMode, ImageSampler, ImageSamplerDescriptor},
     prelude::*,
     render::{
-        mesh::{Indices, MeshVertexAttribute},
+        mesh::MeshVertexAttribute,
         render_asset::RenderAssetUsages,
         render_resource::{PrimitiveTopology, VertexFormat},
     },
     sprite::Material2dPlugin,
 };
+#[cfg(feature = "3d")]
+use materials::{
+    SpineAdditive3dMaterial, SpineAdditivePma3dMaterial, SpineMultiply3dMaterial,
+    SpineMultiplyPma3dMaterial, SpineNormal3dMaterial, SpineNormalPma3dMaterial,
+    SpineScreen3dMaterial, SpineScreenPma3dMaterial, SpineStandardMaterial,
+};
 use materials::{
     SpineAdditiveMaterial, SpineAdditivePmaMaterial, SpineMaterialInfo, SpineMultiplyMaterial,
     SpineMultiplyPmaMaterial, SpineNormalMaterial, SpineNormalPmaMaterial, SpineScreenMaterial,
@@ -27,20 +39,59 @@ use materials::{
 use rusty_spine::{
     atlas::{AtlasFilter, AtlasWrap},
     controller::{SkeletonCombinedRenderable, SkeletonRenderable},
-    AnimationEvent, Physics, Skeleton,
+    AnimationEvent, AnimationState, Physics, Skeleton, TrackEntry,
 };
 use textures::SpineTextureConfig;
 
 use crate::{
-    assets::{AtlasLoader, SkeletonJsonLoader},
-    materials::{SpineMaterialPlugin, DARK_COLOR_ATTRIBUTE, SHADER_HANDLE},
+    assets::{AtlasLoader, CrossfadesLoader, SkeletonJsonLoader},
+    bone_overrides::spine_update_bone_overrides,
+    commands::spine_apply_pending_commands,
+    constraints::spine_update_constraints,
+    draw::{batch_renderables, draw_renderables, DrawBuffers},
+    events::SpineEventRegistry,
+    ik::spine_update_ik_targets,
+    image_attachments::spine_update_slot_images,
+    materials::{SpineMaterialPlugin, DARK_COLOR_ATTRIBUTE, EFFECTS_SHADER_HANDLE, SHADER_HANDLE},
+    mesh_buffers::{write_attribute, write_renderables},
     rusty_spine::{
         controller::SkeletonControllerSettings, draw::CullDirection, AnimationStateData, BoneHandle,
     },
+    skins::spine_update_skins,
+    slot_socket::{socket_splits, update_socket_transform},
+    sprite_sheet::SpineSpriteSheetLoader,
     textures::{SpineTexture, SpineTextureCreateEvent, SpineTextureDisposeEvent, SpineTextures},
+    tint::{spine_apply_tints, spine_restore_tints},
 };
 
-pub use crate::{assets::*, crossfades::Crossfades, entity_sync::*, handle::*, rusty_spine::Color};
+pub use crate::{
+    assets::*,
+    bone_overrides::{
+        SpineBoneOverride, SpineBoneOverrideMode, SpineBoneOverrideSpace, SpineBoneOverrides,
+    },
+    constraints::{
+        SpinePathConstraintMix, SpinePathConstraints, SpinePhysicsConstraints,
+        SpineTransformConstraintMix, SpineTransformConstraints,
+    },
+    crossfades::Crossfades,
+    effects::SpineMaterialEffects,
+    entity_sync::*,
+    handle::*,
+    ik::{SpineIkTarget, SpineIkTargets},
+    image_attachments::{SpineImageAttachment, SpineSlotImages},
+    light_2d::SpineLight2d,
+    physics::{SpinePhysics, SpinePhysicsMode, SpineTeleport},
+    pose::{SpineMixBlend, SpineScrub, SpineScrubBlend},
+    root_motion::{RootMotionDelta, SpineRootMotion},
+    rusty_spine::Color,
+    skins::SpineSkins,
+    slot_socket::SpineSlotSocket,
+    slot_visibility::SpineSlotVisibility,
+    sprite_sheet::{
+        SpineSpriteSheet, SpineSpriteSheetAnimation, SpineSpriteSheetFile, SpineSpriteSheetFrame,
+    },
+    tint::{SpineSlotTint, SpineTint},
+};
 
 /// See [`rusty_spine`] docs for more info.
 pub use crate::rusty_spine::controller::SkeletonController;
@@ -58,9 +109,14 @@ pub enum SpineSystem {
     /// An [`apply_deferred`] to load the spine helper entities this frame.
     SpawnFlush,
     /// Sends [`SpineReadyEvent`] after [`SpineSystem::SpawnFlush`], indicating [`Spine`] components
-    /// on newly spawned [`SpineBundle`]s can now be interacted with.
+    /// on newly spawned [`SpineBundle`]s can now be interacted with. Also applies any
+    /// [`SpinePendingCommands`](`commands::SpinePendingCommands`).
     Ready,
     /// Advances all animations and processes Spine events (see [`SpineEvent`]).
+    ///
+    /// Animations are applied first, then procedural adjustments (such as [`SpineIkTargets`] and
+    /// [`SpineBoneOverrides`]), and skeleton world transforms, including constraints and physics,
+    /// are updated last.
     UpdateAnimation,
     /// Updates all Spine meshes.
     UpdateMeshes,
@@ -123,7 +179,19 @@ impl Plugin for SpinePlugin {
             SpineMaterialPlugin::<SpineScreenPmaMaterial>::default(),
         ))
         .add_plugins(SpineSyncPlugin::first())
+        .register_type::<SpineBoneOverrides>()
+        .register_type::<SpineTransformConstraints>()
+        .register_type::<SpinePathConstraints>()
+        .register_type::<SpinePhysicsConstraints>()
+        .register_type::<SpineSlotImages>()
+        .register_type::<SpineTint>()
+        .register_type::<SpineSlotTint>()
+        .register_type::<SpineSlotVisibility>()
+        .register_type::<SpineMaterialEffects>()
+        .register_type::<SpineLight2d>()
+        .init_resource::<SpineLight2d>()
         .init_resource::<SpineEventQueue>()
+        .init_resource::<SpineEventRegistry>()
         .insert_resource(SpineTextures::init())
         .insert_resource(SpineReadyEvents::default())
         .add_event::<SpineTextureCreateEvent>()
@@ -132,9 +200,13 @@ impl Plugin for SpinePlugin {
         .init_asset::<SkeletonJson>()
         .init_asset::<SkeletonBinary>()
         .init_asset::<SkeletonData>()
+        .init_asset::<CrossfadesAsset>()
+        .init_asset::<SpineSpriteSheet>()
         .init_asset_loader::<AtlasLoader>()
         .init_asset_loader::<SkeletonJsonLoader>()
         .init_asset_loader::<SkeletonBinaryLoader>()
+        .init_asset_loader::<CrossfadesLoader>()
+        .preregister_asset_loader::<SpineSpriteSheetLoader>(SpineSpriteSheetLoader::EXTENSIONS)
         .add_event::<SpineReadyEvent>()
         .add_event::<SpineEvent>()
         .add_systems(
@@ -148,15 +220,48 @@ impl Plugin for SpinePlugin {
                     .in_set(SpineSystem::Ready)
                     .after(SpineSystem::Spawn)
                     .before(SpineSet::OnReady),
+                spine_apply_pending_commands
+                    .in_set(SpineSystem::Ready)
+                    .before(SpineSet::OnReady),
+                spine_update_skins
+                    .in_set(SpineSystem::UpdateAnimation)
+                    .after(SpineSet::OnReady)
+                    .before(spine_update_animation),
+                spine_update_crossfades
+                    .in_set(SpineSystem::UpdateAnimation)
+                    .after(SpineSet::OnReady)
+                    .before(spine_update_animation),
                 spine_update_animation
                     .in_set(SpineSystem::UpdateAnimation)
                     .after(SpineSet::OnReady)
                     .before(SpineSet::OnEvent),
+                spine_update_bone_overrides
+                    .in_set(SpineSystem::UpdateAnimation)
+                    .after(spine_update_animation),
+                spine_update_slot_images
+                    .in_set(SpineSystem::UpdateAnimation)
+                    .after(spine_update_animation)
+                    .before(spine_update_world_transform),
+                (spine_update_ik_targets, spine_update_constraints)
+                    .in_set(SpineSystem::UpdateAnimation)
+                    .after(spine_update_bone_overrides)
+                    .before(spine_update_world_transform),
+                spine_update_world_transform
+                    .in_set(SpineSystem::UpdateAnimation)
+                    .after(spine_update_animation)
+                    .before(SpineSet::OnEvent),
+                spine_apply_tints
+                    .in_set(SpineSystem::UpdateAnimation)
+                    .after(spine_update_animation)
+                    .before(SpineSet::OnEvent),
                 spine_update_meshes
                     .in_set(SpineSystem::UpdateMeshes)
                     .in_set(SpineSet::OnUpdateMesh)
                     .after(SpineSystem::UpdateAnimation)
                     .after(SpineSet::OnEvent),
+                spine_restore_tints
+                    .in_set(SpineSystem::UpdateMeshes)
+                    .after(spine_update_meshes),
                 apply_deferred
                     .in_set(SpineSystem::SpawnFlush)
                     .after(SpineSystem::Spawn)
@@ -168,6 +273,36 @@ impl Plugin for SpinePlugin {
             adjust_spine_textures.in_set(SpineSystem::AdjustSpineTextures),
         );
 
+        load_internal_binary_asset!(
+            app,
+            EFFECTS_SHADER_HANDLE,
+            "spine_effects.wgsl",
+            |bytes: &[u8], path: String| Shader::from_wgsl(
+                std::str::from_utf8(bytes).unwrap().to_owned(),
+                path
+            )
+        );
+        #[cfg(feature = "3d")]
+        app.add_plugins((
+            MaterialPlugin::<SpineStandardMaterial<SpineNormal3dMaterial>>::default(),
+            MaterialPlugin::<SpineStandardMaterial<SpineAdditive3dMaterial>>::default(),
+            MaterialPlugin::<SpineStandardMaterial<SpineMultiply3dMaterial>>::default(),
+            MaterialPlugin::<SpineStandardMaterial<SpineScreen3dMaterial>>::default(),
+            MaterialPlugin::<SpineStandardMaterial<SpineNormalPma3dMaterial>>::default(),
+            MaterialPlugin::<SpineStandardMaterial<SpineAdditivePma3dMaterial>>::default(),
+            MaterialPlugin::<SpineStandardMaterial<SpineMultiplyPma3dMaterial>>::default(),
+            MaterialPlugin::<SpineStandardMaterial<SpineScreenPma3dMaterial>>::default(),
+        ))
+        .add_plugins((
+            SpineMaterialPlugin::<SpineNormal3dMaterial>::default(),
+            SpineMaterialPlugin::<SpineAdditive3dMaterial>::default(),
+            SpineMaterialPlugin::<SpineMultiply3dMaterial>::default(),
+            SpineMaterialPlugin::<SpineScreen3dMaterial>::default(),
+            SpineMaterialPlugin::<SpineNormalPma3dMaterial>::default(),
+            SpineMaterialPlugin::<SpineAdditivePma3dMaterial>::default(),
+            SpineMaterialPlugin::<SpineMultiplyPma3dMaterial>::default(),
+            SpineMaterialPlugin::<SpineScreenPma3dMaterial>::default(),
+        ));
         load_internal_binary_asset!(
             app,
             SHADER_HANDLE,
@@ -177,6 +312,26 @@ impl Plugin for SpinePlugin {
                 path
             )
         );
+        #[cfg(feature = "3d")]
+        load_internal_binary_asset!(
+            app,
+            materials::SHADER_3D_HANDLE,
+            "spine3d.wgsl",
+            |bytes: &[u8], path: String| Shader::from_wgsl(
+                std::str::from_utf8(bytes).unwrap().to_owned(),
+                path
+            )
+        );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // registered once every plugin is built, so that `SpritePlugin` may be added after this
+        let texture_atlas_layouts = app
+            .world()
+            .contains_resource::<Assets<TextureAtlasLayout>>();
+        app.register_asset_loader(SpineSpriteSheetLoader {
+            texture_atlas_layouts,
+        });
     }
 }
 
@@ -189,8 +344,17 @@ struct SpineEventQueue(Arc<Mutex<VecDeque<SpineEvent>>>);
 /// loaded when an entity is spawned. Querying for this component type guarantees that all entities
 /// containing it have a Spine rig that is ready to use.
 #[derive(Component, Debug)]
+#[component(on_replace = spine_on_replace)]
 pub struct Spine(pub SkeletonController);
 
+/// Releases the [`SpineTrackEntryId`]s of the track entries still alive when a [`Spine`] is
+/// removed, since its animation state is disposed without firing dispose events.
+fn spine_on_replace(world: DeferredWorld, entity: Entity, _: ComponentId) {
+    if let Some(spine) = world.get::<Spine>(entity) {
+        SpineTrackEntryId::forget_all(&spine.animation_state);
+    }
+}
+
 /// When loaded, a [`Spine`] entity has children entities attached to it, each containing this
 /// component.
 ///
@@ -313,7 +477,7 @@ impl SpineLoader {
 /// Settings for how this Spine updates and renders.
 ///
 /// Typically set in [`SpineBundle`] when spawning an entity.
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
 pub struct SpineSettings {
     /// Indicates if default Spine materials should be used (default: `true`).
     ///
@@ -324,6 +488,35 @@ pub struct SpineSettings {
     pub mesh_type: SpineMeshType,
     /// The drawer this Spine should use to create its meshes.
     pub drawer: SpineDrawer,
+    /// The z position of the first mesh, relative to the Spine entity (default: `0.`).
+    pub z_offset: f32,
+    /// How far apart the meshes are placed on the z axis (default: `SpineZSpacing::Step(0.001)`).
+    pub z_spacing: SpineZSpacing,
+    /// How meshes interact with lights, shadows and fog.
+    pub lighting: SpineLighting,
+    /// If `true`, atlas pages use normal maps found next to them with an `_n` suffix, such as
+    /// `hero_n.png` for `hero.png`, and meshes get tangents (default: `false`).
+    ///
+    /// Normal maps are provided in [`SpineMaterialInfo::normal_map`] once loaded, and used by the
+    /// built-in materials if [`SpineLighting::lit`] is enabled: 2D meshes are lit by
+    /// [`SpineLight2d`], and 3D meshes (see the `3d` feature) by Bevy's PBR lights.
+    pub normal_maps: bool,
+    /// If `true`, meshes contain normals facing +z (default: `true`).
+    ///
+    /// The built-in materials do not need normals, so this can be disabled to save memory unless
+    /// a custom material uses [`Mesh::ATTRIBUTE_NORMAL`].
+    pub mesh_normals: bool,
+}
+
+impl SpineSettings {
+    /// The z position of the mesh at `index`, out of `mesh_count` meshes.
+    pub(crate) fn mesh_z(&self, index: usize, mesh_count: usize) -> f32 {
+        let step = match self.z_spacing {
+            SpineZSpacing::Step(step) => step,
+            SpineZSpacing::Fit(depth) => depth / mesh_count.max(1) as f32,
+        };
+        self.z_offset + step * index as f32
+    }
 }
 
 /// Mesh types to use in [`SpineSettings`].
@@ -331,8 +524,11 @@ pub struct SpineSettings {
 pub enum SpineMeshType {
     /// Render meshes in 2D.
     Mesh2D,
-    /// Render meshes in 3D. Requires a custom [`SpineMaterial`](`materials::SpineMaterial`) since
-    /// the default materials do not support 3D meshes.
+    /// Render meshes in 3D, using the built-in 3D materials (such as `SpineNormal3dMaterial`)
+    /// which are configured with [`SpineSettings::lighting`].
+    ///
+    /// The built-in 3D materials require the `3d` feature, which depends on Bevy's `bevy_pbr`.
+    /// Without it, a custom [`SpineMaterial`](`materials::SpineMaterial`) is needed.
     Mesh3D,
 }
 
@@ -352,12 +548,73 @@ pub enum SpineDrawer {
     None,
 }
 
+/// Z spacing methods to use in [`SpineSettings`].
+///
+/// Bevy sorts transparent 2D meshes by their z position, so the meshes of a skeleton are spread
+/// out on the z axis to draw in the correct order. Other entities with a z position between the
+/// first and last mesh of a skeleton will be drawn in between its slots.
+///
+/// Bevy sorts transparent 2D meshes by z plus their material's depth bias, with no per-entity
+/// sort key, so a skeleton cannot be sorted against other entities as a single unit. Instead,
+/// reserve a z range for each skeleton with [`SpineZSpacing::Fit`] and keep other entities outside
+/// of it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SpineZSpacing {
+    /// Place each mesh this much further along the z axis than the previous one.
+    Step(f32),
+    /// Spread the meshes evenly within this depth, regardless of how many meshes the skeleton
+    /// has, so that the whole skeleton stays between `z_offset` and `z_offset + depth`.
+    ///
+    /// This only bounds the skeleton's z range. Bevy sorts 2D meshes by z alone, so other
+    /// entities within that range are still drawn in between slots.
+    Fit(f32),
+}
+
+/// Lighting options in [`SpineSettings`].
+///
+/// `lit` and `fog` are only supported by the built-in materials. Shadows and fog only apply to
+/// [`SpineMeshType::Mesh3D`] and require the `3d` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpineLighting {
+    /// If `true`, meshes are shaded by lights facing the skeleton's +z axis (default: `false`).
+    ///
+    /// [`SpineMeshType::Mesh2D`] meshes are lit by the [`SpineLight2d`] resource, and
+    /// [`SpineMeshType::Mesh3D`] meshes by PBR lighting like a `StandardMaterial`, receiving
+    /// shadows.
+    pub lit: bool,
+    /// If `true`, 3D meshes cast shadows (default: `true`).
+    pub cast_shadows: bool,
+    /// If `true`, 3D meshes are affected by `DistanceFog` (default: `true`).
+    pub fog: bool,
+}
+
+impl Default for SpineLighting {
+    fn default() -> Self {
+        Self {
+            lit: false,
+            cast_shadows: true,
+            fog: true,
+        }
+    }
+}
+
+impl Default for SpineZSpacing {
+    fn default() -> Self {
+        Self::Step(0.001)
+    }
+}
+
 impl Default for SpineSettings {
     fn default() -> Self {
         Self {
             default_materials: true,
             mesh_type: SpineMeshType::Mesh2D,
             drawer: SpineDrawer::Combined,
+            z_offset: 0.,
+            z_spacing: SpineZSpacing::default(),
+            lighting: SpineLighting::default(),
+            normal_maps: false,
+            mesh_normals: true,
         }
     }
 }
@@ -479,34 +736,157 @@ pub enum SpineEvent {
     Start {
         entity: Entity,
         animation: String,
+        entry: SpineTrackEntryInfo,
     },
     Interrupt {
         entity: Entity,
         animation: String,
+        entry: SpineTrackEntryInfo,
     },
     End {
         entity: Entity,
         animation: String,
+        entry: SpineTrackEntryInfo,
     },
     Complete {
         entity: Entity,
         animation: String,
+        entry: SpineTrackEntryInfo,
     },
     Dispose {
         entity: Entity,
+        entry: SpineTrackEntryInfo,
     },
     Event {
         entity: Entity,
         name: String,
+        /// The animation time this event was keyed.
+        time: f32,
         int: i32,
         float: f32,
         string: String,
         audio_path: String,
         volume: f32,
         balance: f32,
+        entry: SpineTrackEntryInfo,
     },
 }
 
+/// A unique identifier for a [`TrackEntry`], stable for the lifetime of the entry.
+///
+/// Unlike [`TrackEntryHandle`](`rusty_spine::TrackEntryHandle`), identifiers are never reused, so
+/// they can be used to correlate a [`SpineEvent`] with the call that queued the animation.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpineTrackEntryId};
+/// # fn doc(mut spine: Mut<Spine>, mut spine_events: EventReader<SpineEvent>) {
+/// let track_entry = spine
+///     .animation_state
+///     .set_animation_by_name(0, "jump", false)
+///     .unwrap();
+/// let jump_id = SpineTrackEntryId::of(&track_entry);
+///
+/// // later...
+/// for event in spine_events.read() {
+///     if let SpineEvent::Complete { entry, .. } = event {
+///         if entry.id == jump_id {
+///             println!("jump completed");
+///         }
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpineTrackEntryId(u64);
+
+/// Identifiers of live track entries, keyed by the entry's address. Entries are removed when the
+/// track entry is disposed, so that addresses reused by new entries get new identifiers.
+static TRACK_ENTRY_IDS: LazyLock<Mutex<HashMap<usize, SpineTrackEntryId>>> =
+    LazyLock::new(Default::default);
+
+impl SpineTrackEntryId {
+    /// Get the identifier of a track entry, assigning a new one if necessary.
+    ///
+    /// Identifiers are released when the track entry is disposed or the [`Spine`] is removed.
+    /// Track entries of a [`SkeletonController`] which is not in a [`Spine`] component never
+    /// fire bevy_spine's dispose events, so their identifiers are kept until the process exits.
+    pub fn of(track_entry: &TrackEntry) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        *TRACK_ENTRY_IDS
+            .lock()
+            .unwrap()
+            .entry(track_entry.c_ptr() as usize)
+            .or_insert_with(|| SpineTrackEntryId(NEXT_ID.fetch_add(1, Ordering::Relaxed)))
+    }
+
+    /// Release the identifier of a track entry which is about to be disposed.
+    fn forget(track_entry: &TrackEntry) {
+        TRACK_ENTRY_IDS
+            .lock()
+            .unwrap()
+            .remove(&(track_entry.c_ptr() as usize));
+    }
+
+    /// Release the identifiers of every track entry in an animation state, including queued and
+    /// mixing entries.
+    fn forget_all(animation_state: &AnimationState) {
+        let mut track_entry_ids = TRACK_ENTRY_IDS.lock().unwrap();
+        if track_entry_ids.is_empty() {
+            return;
+        }
+        for track in animation_state.tracks().flatten() {
+            let mut entry = track.c_ptr();
+            // SAFETY: the track entries are owned by the animation state, which is still alive,
+            // and `next` and `mixingFrom` are either null or point to other live entries.
+            unsafe {
+                while !entry.is_null() {
+                    let mut from = (*entry).mixingFrom;
+                    while !from.is_null() {
+                        track_entry_ids.remove(&(from as usize));
+                        from = (*from).mixingFrom;
+                    }
+                    track_entry_ids.remove(&(entry as usize));
+                    entry = (*entry).next;
+                }
+            }
+        }
+    }
+}
+
+/// The state of a [`TrackEntry`] when a [`SpineEvent`] was fired.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpineTrackEntryInfo {
+    /// See [`SpineTrackEntryId`].
+    pub id: SpineTrackEntryId,
+    pub track_index: usize,
+    /// See [`TrackEntry::track_time`].
+    pub track_time: f32,
+    /// See [`TrackEntry::animation_time`].
+    pub animation_time: f32,
+    /// The number of times the animation has completed.
+    pub loop_count: u32,
+    pub looping: bool,
+}
+
+impl SpineTrackEntryInfo {
+    pub fn new(track_entry: &TrackEntry) -> Self {
+        let duration = track_entry.animation_end() - track_entry.animation_start();
+        Self {
+            id: SpineTrackEntryId::of(track_entry),
+            track_index: track_entry.track_index(),
+            track_time: track_entry.track_time(),
+            animation_time: track_entry.animation_time(),
+            loop_count: if duration > 0. {
+                (track_entry.track_time() / duration) as u32
+            } else {
+                0
+            },
+            looping: track_entry.looping(),
+        }
+    }
+}
+
 /// Queued ready events, to be sent after [`SpineSystem::SpawnFlush`].
 #[derive(Default, Resource)]
 struct SpineReadyEvents(Vec<SpineReadyEvent>);
@@ -520,6 +900,7 @@ fn spine_load(
     jsons: Res<Assets<SkeletonJson>>,
     binaries: Res<Assets<SkeletonBinary>>,
     spine_textures: Res<SpineTextures>,
+    spine_event_registry: Res<SpineEventRegistry>,
     asset_server: Res<AssetServer>,
 ) {
     // check if any assets are loading, else, early out to avoid triggering change detection
@@ -537,6 +918,7 @@ fn spine_load(
                 kind,
                 status,
                 premultiplied_alpha,
+                ..
             } = skeleton_data_asset;
             if matches!(status, SkeletonDataStatus::Loading) {
                 let atlas = if let Some(atlas) = atlases.get(atlas_handle) {
@@ -584,6 +966,16 @@ fn spine_load(
                         }
                     }
                 }
+                if let SkeletonDataStatus::Loaded(skeleton_data) = status {
+                    let skeleton_path = match kind {
+                        SkeletonDataKind::JsonFile(handle) => handle.path(),
+                        SkeletonDataKind::BinaryFile(handle) => handle.path(),
+                    };
+                    spine_event_registry.warn_missing(
+                        skeleton_data,
+                        &skeleton_path.map_or_else(|| "<unnamed>".to_owned(), ToString::to_string),
+                    );
+                }
             }
         }
     }
@@ -597,20 +989,26 @@ fn spine_load(
 }
 
 #[allow(clippy::too_many_arguments)]
+#[allow(clippy::type_complexity)]
 fn spine_spawn(
     mut skeleton_query: Query<(
         &mut SpineLoader,
         Entity,
         &SkeletonDataHandle,
         Option<&Crossfades>,
+        Option<&SpineSettings>,
     )>,
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut ready_events: ResMut<SpineReadyEvents>,
     mut skeleton_data_assets: ResMut<Assets<SkeletonData>>,
+    crossfades_assets: Res<Assets<CrossfadesAsset>>,
     spine_event_queue: Res<SpineEventQueue>,
 ) {
-    for (mut spine_loader, spine_entity, data_handle, crossfades) in skeleton_query.iter_mut() {
+    for (mut spine_loader, spine_entity, data_handle, crossfades, settings) in
+        skeleton_query.iter_mut()
+    {
+        let settings = settings.copied().unwrap_or_default();
         if let SpineLoader::Loading { with_children } = spine_loader.as_ref() {
             let skeleton_data_asset =
                 if let Some(skeleton_data_asset) = skeleton_data_assets.get_mut(&data_handle.0) {
@@ -621,9 +1019,19 @@ fn spine_spawn(
             match &skeleton_data_asset.status {
                 SkeletonDataStatus::Loaded(skeleton_data) => {
                     let mut animation_state_data = AnimationStateData::new(skeleton_data.clone());
-                    if let Some(crossfades) = crossfades {
-                        crossfades.apply(&mut animation_state_data);
-                    }
+                    let crossfades_asset = skeleton_data_asset
+                        .crossfades
+                        .as_ref()
+                        .and_then(|handle| crossfades_assets.get(handle));
+                    Crossfades::apply(
+                        &crossfades_asset
+                            .map(|asset| &asset.crossfades)
+                            .into_iter()
+                            .chain(crossfades)
+                            .collect::<Vec<_>>(),
+                        &mut animation_state_data,
+                        skeleton_data,
+                    );
                     let mut controller = SkeletonController::new(
                         skeleton_data.clone(),
                         Arc::new(animation_state_data),
@@ -642,6 +1050,7 @@ fn spine_spawn(
                                 events.push_back(SpineEvent::Start {
                                     entity: spine_entity,
                                     animation: track_entry.animation().name().to_owned(),
+                                    entry: SpineTrackEntryInfo::new(&track_entry),
                                 });
                             }
                             AnimationEvent::Interrupt { track_entry } => {
@@ -649,6 +1058,7 @@ fn spine_spawn(
                                 events.push_back(SpineEvent::Interrupt {
                                     entity: spine_entity,
                                     animation: track_entry.animation().name().to_owned(),
+                                    entry: SpineTrackEntryInfo::new(&track_entry),
                                 });
                             }
                             AnimationEvent::End { track_entry } => {
@@ -656,6 +1066,7 @@ fn spine_spawn(
                                 events.push_back(SpineEvent::End {
                                     entity: spine_entity,
                                     animation: track_entry.animation().name().to_owned(),
+                                    entry: SpineTrackEntryInfo::new(&track_entry),
                                 });
                             }
                             AnimationEvent::Complete { track_entry } => {
@@ -663,16 +1074,22 @@ fn spine_spawn(
                                 events.push_back(SpineEvent::Complete {
                                     entity: spine_entity,
                                     animation: track_entry.animation().name().to_owned(),
+                                    entry: SpineTrackEntryInfo::new(&track_entry),
                                 });
                             }
-                            AnimationEvent::Dispose { .. } => {
+                            AnimationEvent::Dispose { track_entry } => {
                                 let mut events = events.lock().unwrap();
                                 events.push_back(SpineEvent::Dispose {
                                     entity: spine_entity,
+                                    entry: SpineTrackEntryInfo::new(&track_entry),
                                 });
+                                // the track entry is freed after this event
+                                SpineTrackEntryId::forget(&track_entry);
                             }
                             AnimationEvent::Event {
+                                track_entry,
                                 name,
+                                time,
                                 int,
                                 float,
                                 string,
@@ -685,12 +1102,14 @@ fn spine_spawn(
                                 events.push_back(SpineEvent::Event {
                                     entity: spine_entity,
                                     name: name.to_owned(),
+                                    time,
                                     int,
                                     float,
                                     string: string.to_owned(),
                                     audio_path: audio_path.to_owned(),
                                     volume,
                                     balance,
+                                    entry: SpineTrackEntryInfo::new(&track_entry),
                                 });
                             }
                         });
@@ -713,8 +1132,8 @@ fn spine_spawn(
                                         ViewVisibility::default(),
                                     ))
                                     .with_children(|parent| {
-                                        let mut z = 0.;
-                                        for (index, _) in controller.skeleton.slots().enumerate() {
+                                        let slots_count = controller.skeleton.slots_count();
+                                        for index in 0..slots_count {
                                             let mut mesh = Mesh::new(
                                                 PrimitiveTopology::TriangleList,
                                                 RenderAssetUsages::MAIN_WORLD
@@ -729,13 +1148,16 @@ fn spine_spawn(
                                                     handle: mesh_handle.clone(),
                                                     state: SpineMeshState::Empty,
                                                 },
-                                                Transform::from_xyz(0., 0., z),
+                                                Transform::from_xyz(
+                                                    0.,
+                                                    0.,
+                                                    settings.mesh_z(index, slots_count),
+                                                ),
                                                 GlobalTransform::default(),
                                                 Visibility::default(),
                                                 InheritedVisibility::default(),
                                                 ViewVisibility::default(),
                                             ));
-                                            z += 0.001;
                                         }
                                     });
                                 if *with_children {
@@ -826,14 +1248,49 @@ fn spine_ready(
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn spine_update_animation(
-    mut spine_query: Query<(Entity, &mut Spine)>,
+    mut spine_query: Query<(
+        &mut Spine,
+        Option<&mut SpineRootMotion>,
+        Option<&mut Transform>,
+        Option<&mut RootMotionDelta>,
+        Option<&SpineScrub>,
+    )>,
     mut spine_events: EventWriter<SpineEvent>,
     time: Res<Time>,
     spine_event_queue: Res<SpineEventQueue>,
 ) {
-    for (_, mut spine) in spine_query.iter_mut() {
-        spine.update(time.delta_secs(), Physics::Update);
+    for (mut spine, mut root_motion, transform, root_motion_delta, scrub) in spine_query.iter_mut()
+    {
+        if let Some(scrub) = scrub {
+            scrub.apply(&mut spine);
+            continue;
+        }
+        // the first half of `SkeletonController::update`, see `spine_update_world_transform`
+        let Spine(controller) = spine.as_mut();
+        controller.animation_state.update(time.delta_secs());
+        if let Some(root_motion) = root_motion.as_deref_mut() {
+            root_motion.before_apply(controller);
+        }
+        controller.animation_state.apply(&mut controller.skeleton);
+        let Some(mut root_motion) = root_motion else {
+            continue;
+        };
+        let (translation, rotation) = root_motion.after_apply(controller);
+        if let Some(mut root_motion_delta) = root_motion_delta {
+            root_motion_delta.translation = translation;
+            root_motion_delta.rotation = rotation.to_radians();
+        }
+        if let Some(mut transform) = transform.filter(|_| root_motion.apply_to_transform) {
+            if translation != Vec2::ZERO {
+                let offset = transform.rotation * (transform.scale * translation.extend(0.));
+                transform.translation += offset;
+            }
+            if rotation != 0. {
+                transform.rotate_z(rotation.to_radians());
+            }
+        }
     }
     {
         let mut events = spine_event_queue.0.lock().unwrap();
@@ -843,14 +1300,123 @@ fn spine_update_animation(
     }
 }
 
+/// Updates skeleton world transforms (applying constraints and physics) after animations and any
+/// procedural adjustments have been applied.
+#[allow(clippy::type_complexity)]
+fn spine_update_world_transform(
+    mut spine_query: Query<(
+        Entity,
+        &mut Spine,
+        Option<&mut SpinePhysics>,
+        Option<&GlobalTransform>,
+        Has<SpineTeleport>,
+        Has<SpineScrub>,
+    )>,
+    mut removed_physics: RemovedComponents<SpinePhysics>,
+    mut commands: Commands,
+    time: Res<Time>,
+) {
+    for spine_entity in removed_physics.read() {
+        if let Ok((_, mut spine, None, ..)) = spine_query.get_mut(spine_entity) {
+            SpinePhysics::restore_overrides(&mut spine.skeleton);
+        }
+    }
+    for (spine_entity, mut spine, mut spine_physics, global_transform, teleport, scrub) in
+        spine_query.iter_mut()
+    {
+        if teleport {
+            commands.entity(spine_entity).remove::<SpineTeleport>();
+        }
+        let skeleton = &mut spine.skeleton;
+        let physics = match spine_physics.as_deref_mut() {
+            Some(spine_physics) => {
+                spine_physics.before_update(skeleton, global_transform, teleport)
+            }
+            None if teleport => Physics::Reset,
+            None => Physics::Update,
+        };
+        let physics = if scrub && !matches!(physics, Physics::None) {
+            Physics::Pose
+        } else {
+            physics
+        };
+        skeleton.update(time.delta_secs());
+        skeleton.update_world_transform(physics);
+    }
+}
+
+/// Re-applies [`Crossfades`] to live skeletons when the component or the skeleton's
+/// [`CrossfadesAsset`] changes.
+fn spine_update_crossfades(
+    mut spine_query: Query<(
+        Entity,
+        &mut Spine,
+        &SkeletonDataHandle,
+        Option<Ref<Crossfades>>,
+    )>,
+    mut removed_crossfades: RemovedComponents<Crossfades>,
+    mut crossfades_asset_events: EventReader<AssetEvent<CrossfadesAsset>>,
+    skeleton_data_assets: Res<Assets<SkeletonData>>,
+    crossfades_assets: Res<Assets<CrossfadesAsset>>,
+) {
+    let removed_crossfades = removed_crossfades.read().collect::<Vec<_>>();
+    let changed_assets = crossfades_asset_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::Added { id }
+            | AssetEvent::Modified { id }
+            | AssetEvent::Removed { id }
+            | AssetEvent::LoadedWithDependencies { id } => Some(*id),
+            AssetEvent::Unused { .. } => None,
+        })
+        .collect::<Vec<_>>();
+    for (spine_entity, mut spine, data_handle, crossfades) in spine_query.iter_mut() {
+        let Some(skeleton_data_asset) = skeleton_data_assets.get(&data_handle.0) else {
+            continue;
+        };
+        let Some(skeleton_data) = skeleton_data_asset.skeleton_data() else {
+            continue;
+        };
+        let asset_changed = skeleton_data_asset
+            .crossfades
+            .as_ref()
+            .is_some_and(|handle| changed_assets.contains(&handle.id()));
+        let component_changed = crossfades
+            .as_ref()
+            .is_some_and(|crossfades| crossfades.is_changed())
+            || removed_crossfades.contains(&spine_entity);
+        if !asset_changed && !component_changed {
+            continue;
+        }
+        let crossfades_asset = skeleton_data_asset
+            .crossfades
+            .as_ref()
+            .and_then(|handle| crossfades_assets.get(handle));
+        Crossfades::apply(
+            &crossfades_asset
+                .map(|asset| &asset.crossfades)
+                .into_iter()
+                .chain(crossfades.as_deref())
+                .collect::<Vec<_>>(),
+            &mut spine.animation_state.data_mut(),
+            &skeleton_data,
+        );
+    }
+}
+
 pub enum SkeletonRenderableKind {
     Simple(Vec<SkeletonRenderable>),
     Combined(Vec<SkeletonCombinedRenderable>),
 }
 
+#[allow(clippy::too_many_arguments)]
 #[allow(clippy::type_complexity)]
 fn spine_update_meshes(
-    mut spine_query: Query<(&mut Spine, Option<&SpineSettings>)>,
+    mut spine_query: Query<(
+        &mut Spine,
+        Option<&SpineSettings>,
+        Option<&SpineSlotVisibility>,
+    )>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut mesh_query: Query<(
         Entity,
@@ -859,25 +1425,99 @@ fn spine_update_meshes(
         Option<&Mesh2d>,
         Option<&Mesh3d>,
     )>,
+    #[cfg(feature = "3d")] not_shadow_caster_query: Query<(), With<NotShadowCaster>>,
     mut commands: Commands,
     meshes_query: Query<(&Parent, &Children), With<SpineMeshes>>,
+    mut socket_query: Query<
+        (Entity, &Parent, &SpineSlotSocket, &mut Transform),
+        Without<SpineMesh>,
+    >,
     asset_server: Res<AssetServer>,
+    spine_textures: Res<SpineTextures>,
+    mut draw_buffers: Local<DrawBuffers>,
+    mut tangents: Local<Vec<[f32; 4]>>,
 ) {
+    let mut spine_sockets: HashMap<Entity, Vec<Entity>> = HashMap::new();
+    for (socket_entity, socket_parent, ..) in socket_query.iter() {
+        spine_sockets
+            .entry(socket_parent.get())
+            .or_default()
+            .push(socket_entity);
+    }
     for (meshes_parent, meshes_children) in meshes_query.iter() {
-        let Ok((mut spine, spine_mesh_type)) = spine_query.get_mut(meshes_parent.get()) else {
+        let Ok((mut spine, spine_mesh_type, slot_visibility)) =
+            spine_query.get_mut(meshes_parent.get())
+        else {
             continue;
         };
+        let settings = spine_mesh_type.copied().unwrap_or_default();
         let SpineSettings {
-            mesh_type, drawer, ..
-        } = spine_mesh_type.cloned().unwrap_or(SpineSettings::default());
-        let mut renderables = match drawer {
-            SpineDrawer::Combined => {
-                SkeletonRenderableKind::Combined(spine.0.combined_renderables())
+            mesh_type,
+            drawer,
+            normal_maps,
+            mesh_normals,
+            ..
+        } = settings;
+        if drawer == SpineDrawer::None {
+            continue;
+        }
+        let socket_splits = socket_splits(
+            &spine.skeleton,
+            spine_sockets
+                .get(&meshes_parent.get())
+                .into_iter()
+                .flatten()
+                .filter_map(|socket_entity| socket_query.get(*socket_entity).ok())
+                .map(|(socket_entity, _, socket, _)| (socket_entity, socket)),
+        );
+        draw_renderables(
+            &mut spine.0,
+            |_, slot| {
+                slot_visibility
+                    .is_some_and(|slot_visibility| !slot_visibility.is_visible(slot.data().name()))
+            },
+            &mut draw_buffers,
+        );
+        // meshes are built in separate batches before and after each socketed slot
+        let mut segments = vec![];
+        let mut remaining = draw_buffers.renderables();
+        for (draw_index, _) in socket_splits.iter() {
+            let split =
+                remaining.partition_point(|renderable| renderable.slot_index <= *draw_index);
+            let (segment, rest) = remaining.split_at(split);
+            segments.push(segment);
+            remaining = rest;
+        }
+        segments.push(remaining);
+        // each mesh draws a batch of consecutive renderables
+        let segments = segments
+            .into_iter()
+            .map(|renderables| match drawer {
+                SpineDrawer::Combined => batch_renderables(renderables),
+                _ => renderables.chunks(1).collect(),
+            })
+            .collect::<Vec<_>>();
+        // (segment index, renderable index, z index) for each mesh, leaving a z index for sockets
+        let z_count = meshes_children.len() + socket_splits.len();
+        let mut draw_list = vec![];
+        let mut z_index = 0;
+        for (segment_index, segment) in segments.iter().enumerate() {
+            for index in 0..segment.len() {
+                draw_list.push((segment_index, index, z_index));
+                z_index += 1;
             }
-            SpineDrawer::Separated => SkeletonRenderableKind::Simple(spine.0.renderables()),
-            SpineDrawer::None => continue,
-        };
-        let mut z = 0.;
+            if let Some((_, socket_entities)) = socket_splits.get(segment_index) {
+                let z = settings.mesh_z(z_index, z_count);
+                for socket_entity in socket_entities {
+                    if let Ok((_, _, socket, mut socket_transform)) =
+                        socket_query.get_mut(*socket_entity)
+                    {
+                        update_socket_transform(&spine.skeleton, socket, &mut socket_transform, z);
+                    }
+                }
+                z_index += 1;
+            }
+        }
         let mut renderable_index = 0;
         for child in meshes_children.iter() {
             if let Ok((
@@ -917,107 +1557,71 @@ fn spine_update_meshes(
                     Mesh3d(spine_mesh.handle.clone()),
                     Mesh3d
                 );
-                let Some(mesh) = meshes.get_mut(&spine_mesh.handle) else {
-                    continue;
-                };
+                #[cfg(feature = "3d")]
+                {
+                    let not_shadow_caster = not_shadow_caster_query
+                        .contains(spine_mesh_entity)
+                        .then_some(());
+                    apply_mesh!(
+                        not_shadow_caster,
+                        mesh_type == SpineMeshType::Mesh3D && !settings.lighting.cast_shadows,
+                        NotShadowCaster,
+                        NotShadowCaster
+                    );
+                }
                 let mut empty = true;
                 'render: {
-                    let (
-                        slot_index,
-                        attachment_renderer_object,
-                        vertices,
-                        indices,
-                        uvs,
-                        colors,
-                        dark_colors,
-                        blend_mode,
-                        premultiplied_alpha,
-                    ) = match &mut renderables {
-                        SkeletonRenderableKind::Simple(vec) => {
-                            let Some(renderable) = vec.get_mut(renderable_index) else {
-                                break 'render;
-                            };
-                            let colors = vec![
-                                [
-                                    renderable.color.r,
-                                    renderable.color.g,
-                                    renderable.color.b,
-                                    renderable.color.a
-                                ];
-                                renderable.vertices.len()
-                            ];
-                            let dark_colors = vec![
-                                [
-                                    renderable.dark_color.r,
-                                    renderable.dark_color.g,
-                                    renderable.dark_color.b,
-                                    renderable.dark_color.a
-                                ];
-                                renderable.vertices.len()
-                            ];
-                            (
-                                Some(renderable.slot_index),
-                                renderable.attachment_renderer_object,
-                                take(&mut renderable.vertices),
-                                take(&mut renderable.indices),
-                                take(&mut renderable.uvs),
-                                colors,
-                                dark_colors,
-                                renderable.blend_mode,
-                                renderable.premultiplied_alpha,
-                            )
-                        }
-                        SkeletonRenderableKind::Combined(vec) => {
-                            let Some(renderable) = vec.get_mut(renderable_index) else {
-                                break 'render;
-                            };
-                            (
-                                None,
-                                renderable.attachment_renderer_object,
-                                take(&mut renderable.vertices),
-                                take(&mut renderable.indices),
-                                take(&mut renderable.uvs),
-                                take(&mut renderable.colors),
-                                take(&mut renderable.dark_colors),
-                                renderable.blend_mode,
-                                renderable.premultiplied_alpha,
-                            )
-                        }
+                    let Some(&(segment_index, index, z_index)) = draw_list.get(renderable_index)
+                    else {
+                        break 'render;
+                    };
+                    let renderables = segments[segment_index][index];
+                    let Some(first) = renderables.first() else {
+                        break 'render;
                     };
-                    let Some(attachment_render_object) = attachment_renderer_object else {
+                    let Some(attachment_render_object) = first.attachment_renderer_object else {
                         break 'render;
                     };
+                    if !meshes.contains(&spine_mesh.handle) {
+                        break 'render;
+                    }
                     let spine_texture =
                         unsafe { &mut *(attachment_render_object as *mut SpineTexture) };
-                    let texture_path = spine_texture.0.clone();
-                    let mut normals = vec![];
-                    for _ in 0..vertices.len() {
-                        normals.push([0., 0., 0.]);
-                    }
-                    mesh.insert_indices(Indices::U16(indices));
-                    mesh.insert_attribute(
-                        MeshVertexAttribute::new("Vertex_Position", 0, VertexFormat::Float32x2),
-                        vertices,
+                    let (texture, normal_map) = match spine_texture {
+                        SpineTexture::Path(path) => (
+                            asset_server.load(path.as_str()),
+                            normal_maps
+                                .then(|| spine_textures.normal_map(&asset_server, path))
+                                .flatten(),
+                        ),
+                        SpineTexture::Image(image) => (image.clone(), None),
+                    };
+                    write_renderables(
+                        &mut meshes,
+                        &spine_mesh.handle,
+                        renderables,
+                        mesh_normals,
+                        normal_maps.then_some(&mut *tangents),
                     );
-                    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-                    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
-                    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
-                    mesh.insert_attribute(DARK_COLOR_ATTRIBUTE, dark_colors);
                     spine_mesh.state = SpineMeshState::Renderable {
                         info: SpineMaterialInfo {
-                            slot_index,
-                            texture: asset_server.load(texture_path),
-                            blend_mode,
-                            premultiplied_alpha,
+                            slot_index: (drawer != SpineDrawer::Combined)
+                                .then_some(first.slot_index),
+                            texture,
+                            normal_map,
+                            blend_mode: first.blend_mode,
+                            premultiplied_alpha: first.premultiplied_alpha,
                         },
                     };
-                    spine_mesh_transform.translation.z = z;
-                    z += 0.001;
+                    spine_mesh_transform.translation.z = settings.mesh_z(z_index, z_count);
                     empty = false;
                 }
-                if empty {
+                // empty meshes are only rewritten once, so that they are not reuploaded every frame
+                if empty && !matches!(spine_mesh.state, SpineMeshState::Empty) {
                     spine_mesh.state = SpineMeshState::Empty;
-                    empty_mesh(mesh);
+                    if let Some(mesh) = meshes.get_mut(&spine_mesh.handle) {
+                        empty_mesh(mesh);
+                    }
                 }
                 renderable_index += 1;
             }
@@ -1025,27 +1629,19 @@ fn spine_update_meshes(
     }
 }
 
-fn empty_mesh(mesh: &mut Mesh) {
-    let positions: Vec<[f32; 3]> = vec![[0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]];
-    let normals: Vec<[f32; 3]> = vec![[0.0, 0.0, 0.0], [0.0, 0.0, 0.0], [0.0, 0.0, 0.0]];
-    let uvs: Vec<[f32; 2]> = vec![[0.0, 0.0], [0.0, 0.0], [0.0, 0.0]];
-    let colors: Vec<[f32; 4]> = vec![
-        [0.0, 0.0, 0.0, 0.0],
-        [0.0, 0.0, 0.0, 0.0],
-        [0.0, 0.0, 0.0, 0.0],
-    ];
-    let dark_colors: Vec<[f32; 4]> = vec![
-        [0.0, 0.0, 0.0, 0.0],
-        [0.0, 0.0, 0.0, 0.0],
-        [0.0, 0.0, 0.0, 0.0],
-    ];
+/// The vertex position attribute of Spine meshes, which are 2D.
+const POSITION_ATTRIBUTE: MeshVertexAttribute =
+    MeshVertexAttribute::new("Vertex_Position", 0, VertexFormat::Float32x2);
 
+fn empty_mesh(mesh: &mut Mesh) {
+    let zeros = std::iter::repeat_n([0.; 4], 3);
     mesh.remove_indices();
-    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
-    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
-    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
-    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
-    mesh.insert_attribute(DARK_COLOR_ATTRIBUTE, dark_colors);
+    write_attribute(mesh, POSITION_ATTRIBUTE, std::iter::repeat_n([0.; 2], 3));
+    write_attribute(mesh, Mesh::ATTRIBUTE_UV_0, std::iter::repeat_n([0.; 2], 3));
+    write_attribute(mesh, Mesh::ATTRIBUTE_COLOR, zeros.clone());
+    write_attribute(mesh, DARK_COLOR_ATTRIBUTE, zeros);
+    mesh.remove_attribute(Mesh::ATTRIBUTE_NORMAL);
+    mesh.remove_attribute(Mesh::ATTRIBUTE_TANGENT);
 }
 
 #[derive(Default)]
@@ -1136,20 +1732,45 @@ fn adjust_spine_textures(
 }
 
 mod assets;
+mod bone_overrides;
+mod constraints;
 mod crossfades;
+mod draw;
+mod effects;
 mod entity_sync;
 mod handle;
+mod ik;
+mod image_attachments;
+mod light_2d;
+mod mesh_buffers;
+mod normal_maps;
+mod physics;
+mod pose;
+mod root_motion;
+mod skins;
+mod slot_socket;
+mod slot_visibility;
+mod sprite_sheet;
+mod tint;
 
+#[cfg(feature = "audio")]
+pub mod audio;
+pub mod commands;
+pub mod events;
 pub mod materials;
+pub mod raster;
 pub mod textures;
 
 #[doc(hidden)]
 pub mod prelude {
+    pub use crate::commands::SpineEntityCommandsExt;
+    pub use crate::events::{FromSpineEvent, SpineEventAppExt, SpineEventInfo};
     pub use crate::{
-        Crossfades, SkeletonController, SkeletonData, SkeletonDataHandle, Spine, SpineBone,
-        SpineBundle, SpineEvent, SpineLoader, SpineMesh, SpineMeshState, SpinePlugin,
-        SpineReadyEvent, SpineSet, SpineSettings, SpineSync, SpineSyncSet, SpineSyncSystem,
-        SpineSystem,
+        Crossfades, CrossfadesAsset, RootMotionDelta, SkeletonController, SkeletonData,
+        SkeletonDataHandle, Spine, SpineBone, SpineBundle, SpineEvent, SpineIkTarget,
+        SpineIkTargets, SpineLoader, SpineMesh, SpineMeshState, SpinePhysics, SpinePlugin,
+        SpineReadyEvent, SpineRootMotion, SpineScrub, SpineSet, SpineSettings, SpineSkins,
+        SpineSync, SpineSyncSet, SpineSyncSystem, SpineSystem, SpineTeleport, SpineTrackEntryId,
     };
     pub use rusty_spine::{BoneHandle, SlotHandle};
 }
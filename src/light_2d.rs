@@ -0,0 +1,80 @@
+//! Simple lighting for the built-in 2D materials.
+//!
+//! See [`SpineLight2d`].
+
+use bevy::prelude::*;
+
+pub(crate) use uniform::SpineLight2dUniform;
+
+/// The light shading [`SpineMeshType::Mesh2D`](`crate::SpineMeshType::Mesh2D`) skeletons drawn
+/// with the built-in 2D materials, if [`SpineLighting::lit`](`crate::SpineLighting::lit`) is
+/// enabled in their [`SpineSettings`](`crate::SpineSettings`).
+///
+/// Bevy has no 2D lights, so this resource provides an ambient light and a single directional
+/// light shared by all lit skeletons. Meshes face +z, and with
+/// [`SpineSettings::normal_maps`](`crate::SpineSettings::normal_maps`) enabled, their atlas
+/// pages' normal maps are applied on top, following the mesh's bones as they rotate.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::SpineLight2d;
+/// fn follow_mouse(mut light: ResMut<SpineLight2d>, window: Single<&Window>) {
+///     if let Some(cursor) = window.cursor_position() {
+///         let offset = cursor - window.size() / 2.;
+///         // light coming from the cursor towards the screen
+///         light.direction = Vec3::new(-offset.x, offset.y, -window.height()).normalize();
+///     }
+/// }
+/// ```
+///
+/// For other lighting models, such as multiple point lights, use a custom
+/// [`SpineMaterial`](`crate::materials::SpineMaterial`) with
+/// [`SpineMaterialInfo::normal_map`](`crate::materials::SpineMaterialInfo::normal_map`).
+#[derive(Resource, Reflect, Debug, Clone, PartialEq)]
+#[reflect(Resource, Default, Debug, PartialEq)]
+pub struct SpineLight2d {
+    /// The light applied regardless of direction (default: 30% white).
+    pub ambient_color: Color,
+    /// The color of the directional light (default: white).
+    pub color: Color,
+    /// The direction the light travels in world space, where `-z` points into the screen
+    /// (default: `(1., -1., -1.)` normalized, from the top left).
+    pub direction: Vec3,
+}
+
+impl Default for SpineLight2d {
+    fn default() -> Self {
+        Self {
+            ambient_color: Color::linear_rgb(0.3, 0.3, 0.3),
+            color: Color::WHITE,
+            direction: Vec3::new(1., -1., -1.).normalize(),
+        }
+    }
+}
+
+mod uniform {
+    // the field checks generated by `ShaderType` are never called for crate private types
+    #![allow(dead_code)]
+
+    use bevy::{prelude::*, render::render_resource::ShaderType};
+
+    use super::SpineLight2d;
+
+    /// [`SpineLight2d`] as passed to the shaders.
+    #[derive(ShaderType, Default, Debug, Clone)]
+    pub(crate) struct SpineLight2dUniform {
+        ambient_color: LinearRgba,
+        color: LinearRgba,
+        direction: Vec3,
+    }
+
+    impl SpineLight2dUniform {
+        pub(crate) fn new(light: &SpineLight2d) -> Self {
+            Self {
+                ambient_color: light.ambient_color.to_linear(),
+                color: light.color.to_linear(),
+                direction: light.direction.normalize_or(Vec3::NEG_Z),
+            }
+        }
+    }
+}
@@ -18,9 +18,17 @@ use bevy::{
     },
     sprite::{AlphaMode2d, Material2d, Material2dKey},
 };
+#[cfg(feature = "3d")]
+use bevy::{
+    pbr::{ExtendedMaterial, MaterialExtension, MaterialExtensionKey, MaterialExtensionPipeline},
+    render::render_resource::ShaderDefVal,
+};
 use rusty_spine::BlendMode;
 
-use crate::{SpineMesh, SpineMeshState, SpineSettings, SpineSystem};
+use crate::{
+    effects::SpineEffectsUniform, light_2d::SpineLight2dUniform, SpineLight2d,
+    SpineMaterialEffects, SpineMesh, SpineMeshState, SpineMeshType, SpineSettings, SpineSystem,
+};
 
 /// Trait for automatically applying materials to [`SpineMesh`] entities. Used by the built-in
 /// materials but can also be used to create custom materials.
@@ -78,6 +86,9 @@ impl<T: SpineMaterial + Send + Sync + 'static> Plugin for SpineMaterialPlugin<T>
 pub struct SpineMaterialInfo {
     pub slot_index: Option<usize>,
     pub texture: Handle<Image>,
+    /// The normal map of the texture, if [`SpineSettings::normal_maps`] is enabled and it has
+    /// loaded.
+    pub normal_map: Option<Handle<Image>>,
     pub blend_mode: BlendMode,
     pub premultiplied_alpha: bool,
 }
@@ -128,27 +139,76 @@ pub const DARK_COLOR_ATTRIBUTE: MeshVertexAttribute = MeshVertexAttribute::new(
 
 pub const SHADER_HANDLE: Handle<Shader> = Handle::<Shader>::weak_from_u128(10655547040990968849);
 
-/// A [`SystemParam`] to query [`SpineSettings`].
+pub const SHADER_3D_HANDLE: Handle<Shader> = Handle::<Shader>::weak_from_u128(10655547040990968850);
+
+pub const EFFECTS_SHADER_HANDLE: Handle<Shader> =
+    Handle::<Shader>::weak_from_u128(10655547040990968851);
+
+/// The asset type of the built-in 3D materials, such as [`SpineNormal3dMaterial`]. Requires the
+/// `3d` feature.
+///
+/// The texture is stored in [`StandardMaterial::base_color_texture`], and lighting and fog are
+/// configured with [`SpineLighting`](`crate::SpineLighting`).
+#[cfg(feature = "3d")]
+pub type SpineStandardMaterial<T> = ExtendedMaterial<StandardMaterial, T>;
+
+/// A [`SystemParam`] to query [`SpineSettings`], [`SpineMaterialEffects`] and [`SpineLight2d`].
 ///
 /// Mostly used for the built-in materials but may be useful for implementing other materials.
 #[derive(SystemParam)]
 pub struct SpineSettingsQuery<'w, 's> {
     pub spine_settings_query: Query<'w, 's, &'static SpineSettings>,
+    pub spine_effects_query: Query<'w, 's, &'static SpineMaterialEffects>,
+    pub spine_light_2d: Res<'w, SpineLight2d>,
+}
+
+/// The pipeline key of the built-in materials.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpineMaterialKey {
+    effects: bool,
+    lit: bool,
+    normal_map: bool,
 }
 
 macro_rules! material {
-    ($(#[$($attrss:tt)*])* $name:ident, $blend_mode:expr, $premultiplied_alpha:expr, $blend_state:expr) => {
+    ($(#[$($attrss:tt)*])* $name:ident, $name_3d:ident, $blend_mode:expr, $premultiplied_alpha:expr) => {
         $(#[$($attrss)*])*
         #[derive(Asset, Default, AsBindGroup, TypePath, Clone)]
+        #[bind_group_data(SpineMaterialKey)]
         pub struct $name {
             #[texture(0)]
             #[sampler(1)]
             pub image: Handle<Image>,
+            #[uniform(2)]
+            effects: SpineEffectsUniform,
+            #[texture(3)]
+            #[sampler(4)]
+            dissolve_noise: Option<Handle<Image>>,
+            #[uniform(5)]
+            light: SpineLight2dUniform,
+            #[texture(6)]
+            #[sampler(7)]
+            normal_map: Option<Handle<Image>>,
+            effects_enabled: bool,
+            lit: bool,
         }
 
         impl $name {
             pub fn new(image: Handle<Image>) -> Self {
-                Self { image }
+                Self {
+                    image,
+                    ..Default::default()
+                }
+            }
+        }
+
+        impl From<&$name> for SpineMaterialKey {
+            fn from(material: &$name) -> Self {
+                Self {
+                    effects: material.effects_enabled,
+                    lit: material.lit,
+                    normal_map: material.lit && material.normal_map.is_some(),
+                }
             }
         }
 
@@ -168,20 +228,34 @@ macro_rules! material {
             fn specialize(
                 descriptor: &mut RenderPipelineDescriptor,
                 layout: &MeshVertexBufferLayoutRef,
-                _key: Material2dKey<Self>,
+                key: Material2dKey<Self>,
             ) -> Result<(), SpecializedMeshPipelineError> {
-                let vertex_attributes = vec![
+                let mut vertex_attributes = vec![
                     Mesh::ATTRIBUTE_POSITION.at_shader_location(0),
-                    Mesh::ATTRIBUTE_NORMAL.at_shader_location(1),
                     Mesh::ATTRIBUTE_UV_0.at_shader_location(2),
                     Mesh::ATTRIBUTE_COLOR.at_shader_location(4),
                     DARK_COLOR_ATTRIBUTE.at_shader_location(DARK_COLOR_SHADER_POSITION as u32),
                 ];
+                let mut shader_defs = vec![];
+                if key.bind_group_data.lit {
+                    shader_defs.push("SPINE_LIT".into());
+                    // normal maps need the tangents generated with `SpineSettings::normal_maps`
+                    if key.bind_group_data.normal_map && layout.0.contains(Mesh::ATTRIBUTE_TANGENT) {
+                        vertex_attributes.push(Mesh::ATTRIBUTE_TANGENT.at_shader_location(3));
+                        shader_defs.push("SPINE_NORMAL_MAP".into());
+                    }
+                }
                 let vertex_buffer_layout = layout.0.get_layout(&vertex_attributes)?;
                 descriptor.vertex.buffers = vec![vertex_buffer_layout];
+                descriptor.vertex.shader_defs.extend(shader_defs.iter().cloned());
                 if let Some(fragment) = &mut descriptor.fragment {
+                    fragment.shader_defs.extend(shader_defs);
+                    if key.bind_group_data.effects {
+                        fragment.shader_defs.push("SPINE_EFFECTS".into());
+                    }
                     if let Some(target_state) = &mut fragment.targets[0] {
-                        target_state.blend = Some($blend_state);
+                        target_state.blend =
+                            Some(spine_blend_state($blend_mode, $premultiplied_alpha));
                     }
                 }
                 descriptor.primitive.cull_mode = None;
@@ -201,9 +275,136 @@ macro_rules! material {
                 params: &StaticSystemParam<Self::Params<'_, '_>>,
             ) -> Option<Self> {
                 let spine_settings = params.spine_settings_query.get(entity).copied().unwrap_or(SpineSettings::default());
-                if spine_settings.default_materials && renderable_data.blend_mode == $blend_mode && renderable_data.premultiplied_alpha == $premultiplied_alpha {
+                if spine_settings.default_materials && spine_settings.mesh_type == SpineMeshType::Mesh2D && renderable_data.blend_mode == $blend_mode && renderable_data.premultiplied_alpha == $premultiplied_alpha {
                     let mut material = material.unwrap_or_else(|| Self::default());
                     material.image = renderable_data.texture;
+                    let effects = params.spine_effects_query.get(entity).ok();
+                    material.effects_enabled = effects.is_some();
+                    material.effects = effects.map(|effects| SpineEffectsUniform::new(effects, $premultiplied_alpha)).unwrap_or_default();
+                    material.dissolve_noise = effects.and_then(|effects| effects.dissolve_noise.clone());
+                    material.lit = spine_settings.lighting.lit;
+                    material.light = SpineLight2dUniform::new(&params.spine_light_2d);
+                    material.normal_map = renderable_data.normal_map.filter(|_| spine_settings.lighting.lit);
+                    Some(material)
+                } else {
+                    None
+                }
+            }
+        }
+
+        #[doc = concat!("The 3D variant of [`", stringify!($name), "`], used for [`SpineMeshType::Mesh3D`].")]
+        ///
+        /// Extends [`StandardMaterial`] (see [`SpineStandardMaterial`]), so meshes can be lit, cast
+        /// shadows and receive fog depending on [`SpineLighting`](`crate::SpineLighting`).
+        #[cfg(feature = "3d")]
+        #[derive(Asset, Default, AsBindGroup, TypePath, Clone)]
+        #[bind_group_data(SpineMaterialKey)]
+        pub struct $name_3d {
+            #[uniform(100)]
+            effects: SpineEffectsUniform,
+            #[texture(101)]
+            #[sampler(102)]
+            dissolve_noise: Option<Handle<Image>>,
+            effects_enabled: bool,
+        }
+
+        #[cfg(feature = "3d")]
+        impl From<&$name_3d> for SpineMaterialKey {
+            fn from(material: &$name_3d) -> Self {
+                Self {
+                    effects: material.effects_enabled,
+                    lit: false,
+                    normal_map: false,
+                }
+            }
+        }
+
+        #[cfg(feature = "3d")]
+        impl MaterialExtension for $name_3d {
+            fn vertex_shader() -> ShaderRef {
+                SHADER_3D_HANDLE.into()
+            }
+
+            fn fragment_shader() -> ShaderRef {
+                SHADER_3D_HANDLE.into()
+            }
+
+            fn specialize(
+                _pipeline: &MaterialExtensionPipeline,
+                descriptor: &mut RenderPipelineDescriptor,
+                layout: &MeshVertexBufferLayoutRef,
+                key: MaterialExtensionKey<Self>,
+            ) -> Result<(), SpecializedMeshPipelineError> {
+                descriptor.primitive.cull_mode = None;
+                // prepass pipelines (such as shadows) use the standard material's shaders
+                if descriptor.vertex.shader.id() != SHADER_3D_HANDLE.id() {
+                    return Ok(());
+                }
+                let mut vertex_attributes = vec![
+                    Mesh::ATTRIBUTE_POSITION.at_shader_location(0),
+                    Mesh::ATTRIBUTE_UV_0.at_shader_location(2),
+                    Mesh::ATTRIBUTE_COLOR.at_shader_location(4),
+                    DARK_COLOR_ATTRIBUTE.at_shader_location(DARK_COLOR_SHADER_POSITION as u32),
+                ];
+                if layout.0.contains(Mesh::ATTRIBUTE_TANGENT) {
+                    vertex_attributes.push(Mesh::ATTRIBUTE_TANGENT.at_shader_location(3));
+                }
+                let vertex_buffer_layout = layout.0.get_layout(&vertex_attributes)?;
+                descriptor.vertex.buffers = vec![vertex_buffer_layout];
+                if let Some(fragment) = &mut descriptor.fragment {
+                    // the blend state below expects the shader's output as is
+                    fragment.shader_defs.retain(|shader_def| {
+                        !matches!(shader_def, ShaderDefVal::Bool(name, _) if name == "PREMULTIPLY_ALPHA")
+                    });
+                    if key.bind_group_data.effects {
+                        fragment.shader_defs.push("SPINE_EFFECTS".into());
+                    }
+                    if let Some(target_state) = &mut fragment.targets[0] {
+                        target_state.blend =
+                            Some(spine_blend_state($blend_mode, $premultiplied_alpha));
+                    }
+                }
+                Ok(())
+            }
+        }
+
+        #[cfg(feature = "3d")]
+        impl SpineMaterial for $name_3d {
+            type MeshMaterial = MeshMaterial3d<SpineStandardMaterial<Self>>;
+            type Material = SpineStandardMaterial<Self>;
+            type Params<'w, 's> = SpineSettingsQuery<'w, 's>;
+
+            fn update(
+                material: Option<Self::Material>,
+                entity: Entity,
+                renderable_data: SpineMaterialInfo,
+                params: &StaticSystemParam<Self::Params<'_, '_>>,
+            ) -> Option<Self::Material> {
+                let spine_settings = params.spine_settings_query.get(entity).copied().unwrap_or(SpineSettings::default());
+                if spine_settings.default_materials && spine_settings.mesh_type == SpineMeshType::Mesh3D && renderable_data.blend_mode == $blend_mode && renderable_data.premultiplied_alpha == $premultiplied_alpha {
+                    let mut material = material.unwrap_or_else(|| SpineStandardMaterial {
+                        base: StandardMaterial {
+                            alpha_mode: if $premultiplied_alpha {
+                                AlphaMode::Premultiplied
+                            } else {
+                                AlphaMode::Blend
+                            },
+                            perceptual_roughness: 1.,
+                            reflectance: 0.,
+                            double_sided: true,
+                            cull_mode: None,
+                            ..Default::default()
+                        },
+                        extension: Self::default(),
+                    });
+                    material.base.base_color_texture = Some(renderable_data.texture);
+                    material.base.normal_map_texture = renderable_data.normal_map.filter(|_| spine_settings.lighting.lit);
+                    material.base.unlit = !spine_settings.lighting.lit;
+                    material.base.fog_enabled = spine_settings.lighting.fog;
+                    let effects = params.spine_effects_query.get(entity).ok();
+                    material.extension.effects_enabled = effects.is_some();
+                    material.extension.effects = effects.map(|effects| SpineEffectsUniform::new(effects, $premultiplied_alpha)).unwrap_or_default();
+                    material.extension.dissolve_noise = effects.and_then(|effects| effects.dissolve_noise.clone());
                     Some(material)
                 } else {
                     None
@@ -213,154 +414,168 @@ macro_rules! material {
     };
 }
 
+/// The blend state used by the built-in materials for a blend mode.
+pub(crate) fn spine_blend_state(blend_mode: BlendMode, premultiplied_alpha: bool) -> BlendState {
+    match (blend_mode, premultiplied_alpha) {
+        (BlendMode::Normal, false) => BlendState {
+            color: BlendComponent {
+                src_factor: BlendFactor::SrcAlpha,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+            alpha: BlendComponent {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+        },
+        (BlendMode::Additive, false) => BlendState {
+            color: BlendComponent {
+                src_factor: BlendFactor::SrcAlpha,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+            alpha: BlendComponent {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+        },
+        (BlendMode::Multiply, false) => BlendState {
+            color: BlendComponent {
+                src_factor: BlendFactor::Dst,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+            alpha: BlendComponent {
+                src_factor: BlendFactor::OneMinusSrcAlpha,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+        },
+        (BlendMode::Screen, false) => BlendState {
+            color: BlendComponent {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+            alpha: BlendComponent {
+                src_factor: BlendFactor::OneMinusSrc,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+        },
+        (BlendMode::Normal, true) => BlendState {
+            color: BlendComponent {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+            alpha: BlendComponent {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+        },
+        (BlendMode::Additive, true) => BlendState {
+            color: BlendComponent {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+            alpha: BlendComponent {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::One,
+                operation: BlendOperation::Add,
+            },
+        },
+        (BlendMode::Multiply, true) => BlendState {
+            color: BlendComponent {
+                src_factor: BlendFactor::Dst,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+            alpha: BlendComponent {
+                src_factor: BlendFactor::OneMinusSrcAlpha,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+        },
+        (BlendMode::Screen, true) => BlendState {
+            color: BlendComponent {
+                src_factor: BlendFactor::One,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+            alpha: BlendComponent {
+                src_factor: BlendFactor::OneMinusSrc,
+                dst_factor: BlendFactor::OneMinusSrcAlpha,
+                operation: BlendOperation::Add,
+            },
+        },
+    }
+}
+
 material!(
     /// Normal blend mode material, non-premultiplied-alpha
     SpineNormalMaterial,
+    SpineNormal3dMaterial,
     BlendMode::Normal,
-    false,
-    BlendState {
-        color: BlendComponent {
-            src_factor: BlendFactor::SrcAlpha,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
-            operation: BlendOperation::Add,
-        },
-        alpha: BlendComponent {
-            src_factor: BlendFactor::One,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
-            operation: BlendOperation::Add,
-        },
-    }
+    false
 );
 
 material!(
     /// Additive blend mode material, non-premultiplied-alpha
     SpineAdditiveMaterial,
+    SpineAdditive3dMaterial,
     BlendMode::Additive,
-    false,
-    BlendState {
-        color: BlendComponent {
-            src_factor: BlendFactor::SrcAlpha,
-            dst_factor: BlendFactor::One,
-            operation: BlendOperation::Add,
-        },
-        alpha: BlendComponent {
-            src_factor: BlendFactor::One,
-            dst_factor: BlendFactor::One,
-            operation: BlendOperation::Add,
-        },
-    }
+    false
 );
 
 material!(
     /// Multiply blend mode material, non-premultiplied-alpha
     SpineMultiplyMaterial,
+    SpineMultiply3dMaterial,
     BlendMode::Multiply,
-    false,
-    BlendState {
-        color: BlendComponent {
-            src_factor: BlendFactor::Dst,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
-            operation: BlendOperation::Add,
-        },
-        alpha: BlendComponent {
-            src_factor: BlendFactor::OneMinusSrcAlpha,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
-            operation: BlendOperation::Add,
-        },
-    }
+    false
 );
 
 material!(
     /// Screen blend mode material, non-premultiplied-alpha
     SpineScreenMaterial,
+    SpineScreen3dMaterial,
     BlendMode::Screen,
-    false,
-    BlendState {
-        color: BlendComponent {
-            src_factor: BlendFactor::One,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
-            operation: BlendOperation::Add,
-        },
-        alpha: BlendComponent {
-            src_factor: BlendFactor::OneMinusSrc,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
-            operation: BlendOperation::Add,
-        },
-    }
+    false
 );
 
 material!(
     /// Normal blend mode material, premultiplied-alpha
     SpineNormalPmaMaterial,
+    SpineNormalPma3dMaterial,
     BlendMode::Normal,
-    true,
-    BlendState {
-        color: BlendComponent {
-            src_factor: BlendFactor::One,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
-            operation: BlendOperation::Add,
-        },
-        alpha: BlendComponent {
-            src_factor: BlendFactor::One,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
-            operation: BlendOperation::Add,
-        },
-    }
+    true
 );
 
 material!(
     /// Additive blend mode material, premultiplied-alpha
     SpineAdditivePmaMaterial,
+    SpineAdditivePma3dMaterial,
     BlendMode::Additive,
-    true,
-    BlendState {
-        color: BlendComponent {
-            src_factor: BlendFactor::One,
-            dst_factor: BlendFactor::One,
-            operation: BlendOperation::Add,
-        },
-        alpha: BlendComponent {
-            src_factor: BlendFactor::One,
-            dst_factor: BlendFactor::One,
-            operation: BlendOperation::Add,
-        },
-    }
+    true
 );
 
 material!(
     /// Multiply blend mode material, premultiplied-alpha
     SpineMultiplyPmaMaterial,
+    SpineMultiplyPma3dMaterial,
     BlendMode::Multiply,
-    true,
-    BlendState {
-        color: BlendComponent {
-            src_factor: BlendFactor::Dst,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
-            operation: BlendOperation::Add,
-        },
-        alpha: BlendComponent {
-            src_factor: BlendFactor::OneMinusSrcAlpha,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
-            operation: BlendOperation::Add,
-        },
-    }
+    true
 );
 
 material!(
     /// Screen blend mode material, premultiplied-alpha
     SpineScreenPmaMaterial,
+    SpineScreenPma3dMaterial,
     BlendMode::Screen,
-    true,
-    BlendState {
-        color: BlendComponent {
-            src_factor: BlendFactor::One,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
-            operation: BlendOperation::Add,
-        },
-        alpha: BlendComponent {
-            src_factor: BlendFactor::OneMinusSrc,
-            dst_factor: BlendFactor::OneMinusSrcAlpha,
-            operation: BlendOperation::Add,
-        },
-    }
+    true
 );
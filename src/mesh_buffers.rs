@@ -0,0 +1,294 @@
+//! Writing Spine mesh data into existing mesh buffers.
+
+use bevy::{
+    asset::{Assets, Handle},
+    render::mesh::{Indices, Mesh, MeshVertexAttribute, VertexAttributeValues},
+};
+use rusty_spine::controller::SkeletonRenderable;
+
+use crate::{materials::DARK_COLOR_ATTRIBUTE, normal_maps::generate_tangents, POSITION_ATTRIBUTE};
+
+/// Writes a batch of consecutive renderables into a mesh, offsetting the indices of each
+/// renderable past the vertices of the previous ones.
+///
+/// The mesh is only borrowed mutably, which marks it as modified and uploads it to the GPU again,
+/// if any of its contents changed. Tangents are generated into `tangents`, a buffer reused across
+/// meshes, when it is `Some`.
+pub(crate) fn write_renderables(
+    meshes: &mut Assets<Mesh>,
+    handle: &Handle<Mesh>,
+    renderables: &[SkeletonRenderable],
+    normals: bool,
+    tangents: Option<&mut Vec<[f32; 4]>>,
+) {
+    let tangents = tangents.map(|tangents| {
+        tangents.clear();
+        for renderable in renderables {
+            generate_tangents(
+                &renderable.vertices,
+                &renderable.uvs,
+                &renderable.indices,
+                tangents,
+            );
+        }
+        tangents.iter().copied()
+    });
+    let vertex_count = renderables
+        .iter()
+        .map(|renderable| renderable.vertices.len())
+        .sum();
+    let indices = renderables
+        .iter()
+        .scan(0, |index_offset, renderable| {
+            let renderable_offset = *index_offset;
+            *index_offset += renderable.vertices.len() as u32;
+            Some((renderable_offset, renderable))
+        })
+        .flat_map(|(index_offset, renderable)| {
+            renderable
+                .indices
+                .iter()
+                .map(move |index| index_offset + *index as u32)
+        });
+    let positions = renderables
+        .iter()
+        .flat_map(|renderable| renderable.vertices.iter().copied());
+    let uvs = renderables
+        .iter()
+        .flat_map(|renderable| renderable.uvs.iter().copied());
+    let colors = renderables.iter().flat_map(|renderable| {
+        let color = renderable.color;
+        std::iter::repeat_n(
+            [color.r, color.g, color.b, color.a],
+            renderable.vertices.len(),
+        )
+    });
+    let dark_colors = renderables.iter().flat_map(|renderable| {
+        let dark_color = renderable.dark_color;
+        std::iter::repeat_n(
+            [dark_color.r, dark_color.g, dark_color.b, dark_color.a],
+            renderable.vertices.len(),
+        )
+    });
+    let normals = normals.then(|| std::iter::repeat_n([0., 0., 1.], vertex_count));
+
+    let unchanged = meshes.get(handle).is_some_and(|mesh| {
+        indices_match(mesh, indices.clone(), vertex_count)
+            && attribute_matches(mesh, POSITION_ATTRIBUTE, Some(positions.clone()))
+            && attribute_matches(mesh, Mesh::ATTRIBUTE_UV_0, Some(uvs.clone()))
+            && attribute_matches(mesh, Mesh::ATTRIBUTE_COLOR, Some(colors.clone()))
+            && attribute_matches(mesh, DARK_COLOR_ATTRIBUTE, Some(dark_colors.clone()))
+            && attribute_matches(mesh, Mesh::ATTRIBUTE_NORMAL, normals.clone())
+            && attribute_matches(mesh, Mesh::ATTRIBUTE_TANGENT, tangents.clone())
+    });
+    if unchanged {
+        return;
+    }
+    let Some(mesh) = meshes.get_mut(handle) else {
+        return;
+    };
+    write_indices(mesh, indices, vertex_count);
+    write_attribute(mesh, POSITION_ATTRIBUTE, positions);
+    write_attribute(mesh, Mesh::ATTRIBUTE_UV_0, uvs);
+    write_attribute(mesh, Mesh::ATTRIBUTE_COLOR, colors);
+    write_attribute(mesh, DARK_COLOR_ATTRIBUTE, dark_colors);
+    match normals {
+        Some(normals) => write_attribute(mesh, Mesh::ATTRIBUTE_NORMAL, normals),
+        None => {
+            mesh.remove_attribute(Mesh::ATTRIBUTE_NORMAL);
+        }
+    }
+    match tangents {
+        Some(tangents) => write_attribute(mesh, Mesh::ATTRIBUTE_TANGENT, tangents),
+        None => {
+            mesh.remove_attribute(Mesh::ATTRIBUTE_TANGENT);
+        }
+    }
+}
+
+/// Vertex attribute values which can be written in place.
+pub(crate) trait AttributeValue: Copy + PartialEq {
+    fn values(values: &VertexAttributeValues) -> Option<&[Self]>;
+
+    fn values_mut(values: &mut VertexAttributeValues) -> Option<&mut Vec<Self>>;
+}
+
+impl AttributeValue for [f32; 2] {
+    fn values(values: &VertexAttributeValues) -> Option<&[Self]> {
+        match values {
+            VertexAttributeValues::Float32x2(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    fn values_mut(values: &mut VertexAttributeValues) -> Option<&mut Vec<Self>> {
+        match values {
+            VertexAttributeValues::Float32x2(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+impl AttributeValue for [f32; 3] {
+    fn values(values: &VertexAttributeValues) -> Option<&[Self]> {
+        match values {
+            VertexAttributeValues::Float32x3(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    fn values_mut(values: &mut VertexAttributeValues) -> Option<&mut Vec<Self>> {
+        match values {
+            VertexAttributeValues::Float32x3(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+impl AttributeValue for [f32; 4] {
+    fn values(values: &VertexAttributeValues) -> Option<&[Self]> {
+        match values {
+            VertexAttributeValues::Float32x4(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    fn values_mut(values: &mut VertexAttributeValues) -> Option<&mut Vec<Self>> {
+        match values {
+            VertexAttributeValues::Float32x4(values) => Some(values),
+            _ => None,
+        }
+    }
+}
+
+/// Whether a mesh attribute contains exactly `values`, or is missing when `values` is `None`.
+fn attribute_matches<T: AttributeValue>(
+    mesh: &Mesh,
+    attribute: MeshVertexAttribute,
+    values: Option<impl Iterator<Item = T>>,
+) -> bool {
+    match (mesh.attribute(attribute.id), values) {
+        (Some(existing), Some(values)) => {
+            T::values(existing).is_some_and(|existing| existing.iter().copied().eq(values))
+        }
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Writes `values` into a mesh attribute, reusing its buffer. Attributes which already contain
+/// the same values are left untouched.
+///
+/// This only saves copying on the CPU, since the mesh was already marked as modified when it was
+/// borrowed mutably from [`Assets`](`bevy::asset::Assets`).
+pub(crate) fn write_attribute<T: AttributeValue>(
+    mesh: &mut Mesh,
+    attribute: MeshVertexAttribute,
+    values: impl Iterator<Item = T> + Clone,
+) where
+    Vec<T>: Into<VertexAttributeValues>,
+{
+    match mesh.attribute_mut(attribute.id).and_then(T::values_mut) {
+        Some(existing) => {
+            if !existing.iter().copied().eq(values.clone()) {
+                existing.clear();
+                existing.extend(values);
+            }
+        }
+        None => mesh.insert_attribute(attribute, values.collect::<Vec<T>>()),
+    }
+}
+
+/// Index values which can be written in place.
+pub(crate) trait IndexValue: Copy + PartialEq {
+    fn values(indices: &Indices) -> Option<&[Self]>;
+
+    fn values_mut(indices: &mut Indices) -> Option<&mut Vec<Self>>;
+
+    fn into_indices(values: Vec<Self>) -> Indices;
+}
+
+impl IndexValue for u16 {
+    fn values(indices: &Indices) -> Option<&[Self]> {
+        match indices {
+            Indices::U16(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    fn values_mut(indices: &mut Indices) -> Option<&mut Vec<Self>> {
+        match indices {
+            Indices::U16(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    fn into_indices(values: Vec<Self>) -> Indices {
+        Indices::U16(values)
+    }
+}
+
+impl IndexValue for u32 {
+    fn values(indices: &Indices) -> Option<&[Self]> {
+        match indices {
+            Indices::U32(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    fn values_mut(indices: &mut Indices) -> Option<&mut Vec<Self>> {
+        match indices {
+            Indices::U32(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    fn into_indices(values: Vec<Self>) -> Indices {
+        Indices::U32(values)
+    }
+}
+
+/// Writes `indices` into the mesh's index buffer, reusing it if it has the same format. Indices
+/// which are unchanged are left untouched.
+///
+/// 16-bit indices are used when every vertex can be addressed by them, and 32-bit indices
+/// otherwise.
+pub(crate) fn write_indices(
+    mesh: &mut Mesh,
+    indices: impl Iterator<Item = u32> + Clone,
+    vertex_count: usize,
+) {
+    if vertex_count <= u16::MAX as usize + 1 {
+        write_index_values(mesh, indices.map(|index| index as u16));
+    } else {
+        write_index_values(mesh, indices);
+    }
+}
+
+/// Whether the mesh's index buffer contains exactly `indices`, in the format [`write_indices`]
+/// would write them in.
+fn indices_match(mesh: &Mesh, indices: impl Iterator<Item = u32>, vertex_count: usize) -> bool {
+    if vertex_count <= u16::MAX as usize + 1 {
+        index_values_match(mesh, indices.map(|index| index as u16))
+    } else {
+        index_values_match(mesh, indices)
+    }
+}
+
+fn index_values_match<T: IndexValue>(mesh: &Mesh, indices: impl Iterator<Item = T>) -> bool {
+    mesh.indices()
+        .and_then(T::values)
+        .is_some_and(|existing| existing.iter().copied().eq(indices))
+}
+
+fn write_index_values<T: IndexValue>(mesh: &mut Mesh, indices: impl Iterator<Item = T> + Clone) {
+    match mesh.indices_mut().and_then(T::values_mut) {
+        Some(existing) => {
+            if !existing.iter().copied().eq(indices.clone()) {
+                existing.clear();
+                existing.extend(indices);
+            }
+        }
+        None => mesh.insert_indices(T::into_indices(indices.collect())),
+    }
+}
@@ -0,0 +1,84 @@
+//! Normal maps for atlas pages and tangents for Spine meshes.
+//!
+//! See [`SpineSettings::normal_maps`](`crate::SpineSettings::normal_maps`).
+
+/// The path of the normal map for an atlas page, such as `hero_n.png` for `hero.png`.
+pub(crate) fn normal_map_path(page_path: &str) -> String {
+    let file_start = page_path.rfind('/').map(|index| index + 1).unwrap_or(0);
+    match page_path[file_start..].rfind('.') {
+        Some(extension_index) => {
+            let (stem, extension) = page_path.split_at(file_start + extension_index);
+            format!("{stem}_n{extension}")
+        }
+        None => format!("{page_path}_n"),
+    }
+}
+
+/// Generates a tangent for each vertex of a flat mesh facing +z, pointing towards increasing u
+/// in the mesh's local space. The `w` component is the handedness of the bitangent, which points
+/// towards decreasing v (up in the texture), as expected by Bevy's PBR shaders.
+///
+/// Since tangents follow the deformed vertices and their uvs, they stay correct for rotated and
+/// scaled bones as well as rotated atlas regions.
+///
+/// The tangents are appended to `tangents`, so that its buffer can be reused across meshes.
+pub(crate) fn generate_tangents(
+    vertices: &[[f32; 2]],
+    uvs: &[[f32; 2]],
+    indices: &[u16],
+    tangents: &mut Vec<[f32; 4]>,
+) {
+    let start = tangents.len();
+    // accumulated as [tangent x, tangent y, bitangent x, bitangent y] until normalized below
+    tangents.resize(start + vertices.len(), [0.; 4]);
+    let accumulated = &mut tangents[start..];
+    for triangle in indices.chunks_exact(3) {
+        let [i0, i1, i2] = [
+            triangle[0] as usize,
+            triangle[1] as usize,
+            triangle[2] as usize,
+        ];
+        let edge1 = [
+            vertices[i1][0] - vertices[i0][0],
+            vertices[i1][1] - vertices[i0][1],
+        ];
+        let edge2 = [
+            vertices[i2][0] - vertices[i0][0],
+            vertices[i2][1] - vertices[i0][1],
+        ];
+        let (du1, dv1) = (uvs[i1][0] - uvs[i0][0], uvs[i1][1] - uvs[i0][1]);
+        let (du2, dv2) = (uvs[i2][0] - uvs[i0][0], uvs[i2][1] - uvs[i0][1]);
+        let determinant = du1 * dv2 - du2 * dv1;
+        if determinant.abs() < f32::EPSILON {
+            continue;
+        }
+        let r = determinant.recip();
+        let tangent = [
+            (edge1[0] * dv2 - edge2[0] * dv1) * r,
+            (edge1[1] * dv2 - edge2[1] * dv1) * r,
+        ];
+        let bitangent = [
+            (edge2[0] * du1 - edge1[0] * du2) * r,
+            (edge2[1] * du1 - edge1[1] * du2) * r,
+        ];
+        for index in [i0, i1, i2] {
+            accumulated[index][0] += tangent[0];
+            accumulated[index][1] += tangent[1];
+            accumulated[index][2] += bitangent[0];
+            accumulated[index][3] += bitangent[1];
+        }
+    }
+    for value in accumulated {
+        let [tangent_x, tangent_y, bitangent_x, bitangent_y] = *value;
+        let length = (tangent_x * tangent_x + tangent_y * tangent_y).sqrt();
+        if length < f32::EPSILON {
+            *value = [1., 0., 0., 1.];
+            continue;
+        }
+        let tangent = [tangent_x / length, tangent_y / length];
+        // cross((0, 0, 1), tangent) compared to the bitangent pointing up in the texture
+        let handedness = -tangent[1] * -bitangent_x + tangent[0] * -bitangent_y;
+        let w = if handedness < 0. { -1. } else { 1. };
+        *value = [tangent[0], tangent[1], 0., w];
+    }
+}
@@ -0,0 +1,242 @@
+//! Per-skeleton control of Spine physics constraints.
+//!
+//! See [`SpinePhysics`].
+
+use bevy::prelude::*;
+use rusty_spine::{
+    c::{spSkeleton_physicsRotate, spSkeleton_physicsTranslate},
+    Physics, Skeleton,
+};
+
+/// How physics constraints are updated, see [`SpinePhysics`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SpinePhysicsMode {
+    /// Physics are not updated or applied.
+    None,
+    /// Physics are reset to the current pose.
+    Reset,
+    /// Physics are updated and the pose from physics is applied.
+    #[default]
+    Update,
+    /// Physics are not updated but the pose from physics is applied.
+    Pose,
+}
+
+impl From<SpinePhysicsMode> for Physics {
+    fn from(mode: SpinePhysicsMode) -> Self {
+        match mode {
+            SpinePhysicsMode::None => Physics::None,
+            SpinePhysicsMode::Reset => Physics::Reset,
+            SpinePhysicsMode::Update => Physics::Update,
+            SpinePhysicsMode::Pose => Physics::Pose,
+        }
+    }
+}
+
+/// Controls how the physics constraints of a [`Spine`](`crate::Spine`) are simulated.
+///
+/// Without this component, physics are always updated ([`SpinePhysicsMode::Update`]) and only
+/// react to animated bone motion. With it, the movement of the entity's [`GlobalTransform`] is
+/// also fed into the simulation, so hair and capes trail behind a character moving through the
+/// world.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpinePhysics};
+/// # fn doc(mut commands: Commands) {
+/// commands.spawn((
+///     SpineBundle {
+///         // ..
+///         ..Default::default()
+///     },
+///     SpinePhysics::default().with_wind(2.).with_inertia(0.5),
+/// ));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct SpinePhysics {
+    /// How physics are updated (default: [`SpinePhysicsMode::Update`]).
+    pub mode: SpinePhysicsMode,
+    /// Overrides the wind of every physics constraint, including animated values (default:
+    /// `None`). When cleared, or when this component is removed, the wind is restored to the
+    /// setup pose.
+    pub wind: Option<f32>,
+    /// Overrides the gravity of every physics constraint, including animated values (default:
+    /// `None`). When cleared, or when this component is removed, the gravity is restored to the
+    /// setup pose.
+    pub gravity: Option<f32>,
+    /// Scales how strongly entity movement affects physics, where `0.` ignores it entirely
+    /// (default: `1.`).
+    pub inertia: f32,
+    /// If the entity moves further than this distance (in world units) in a single frame, it is
+    /// treated as a teleport, see [`SpineTeleport`] (default: `None`).
+    pub teleport_distance: Option<f32>,
+    last_transform: Option<GlobalTransform>,
+    wind_overridden: bool,
+    gravity_overridden: bool,
+}
+
+impl Default for SpinePhysics {
+    fn default() -> Self {
+        Self {
+            mode: SpinePhysicsMode::Update,
+            wind: None,
+            gravity: None,
+            inertia: 1.,
+            teleport_distance: None,
+            last_transform: None,
+            wind_overridden: false,
+            gravity_overridden: false,
+        }
+    }
+}
+
+impl SpinePhysics {
+    pub fn new(mode: SpinePhysicsMode) -> Self {
+        Self {
+            mode,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_wind(mut self, wind: f32) -> Self {
+        self.wind = Some(wind);
+        self
+    }
+
+    pub fn with_gravity(mut self, gravity: f32) -> Self {
+        self.gravity = Some(gravity);
+        self
+    }
+
+    pub fn with_inertia(mut self, inertia: f32) -> Self {
+        self.inertia = inertia;
+        self
+    }
+
+    pub fn with_teleport_distance(mut self, teleport_distance: f32) -> Self {
+        self.teleport_distance = Some(teleport_distance);
+        self
+    }
+
+    /// The [`Physics`] to update the skeleton's world transform with.
+    pub(crate) fn physics(&self) -> Physics {
+        self.mode.into()
+    }
+
+    /// Called after animations are applied, before the skeleton's world transform is updated.
+    /// Returns the [`Physics`] to update the world transform with.
+    pub(crate) fn before_update(
+        &mut self,
+        skeleton: &mut Skeleton,
+        global_transform: Option<&GlobalTransform>,
+        teleport: bool,
+    ) -> Physics {
+        // cleared overrides are restored to the setup pose once, animations take over afterwards
+        let restore_wind = self.wind.is_none() && self.wind_overridden;
+        let restore_gravity = self.gravity.is_none() && self.gravity_overridden;
+        self.wind_overridden = self.wind.is_some();
+        self.gravity_overridden = self.gravity.is_some();
+        for mut physics_constraint in skeleton.physics_constraints_mut() {
+            if let Some(wind) = self.wind {
+                physics_constraint.set_wind(wind);
+            } else if restore_wind {
+                let wind = physics_constraint.data().wind();
+                physics_constraint.set_wind(wind);
+            }
+            if let Some(gravity) = self.gravity {
+                physics_constraint.set_gravity(gravity);
+            } else if restore_gravity {
+                let gravity = physics_constraint.data().gravity();
+                physics_constraint.set_gravity(gravity);
+            }
+        }
+        let last_transform = match global_transform {
+            Some(global_transform) => self.last_transform.replace(*global_transform),
+            None => self.last_transform.take(),
+        };
+        let (Some(global_transform), Some(last_transform)) = (global_transform, last_transform)
+        else {
+            return self.teleport_physics(teleport);
+        };
+        let teleport = teleport
+            || self.teleport_distance.is_some_and(|teleport_distance| {
+                global_transform
+                    .translation()
+                    .distance(last_transform.translation())
+                    > teleport_distance
+            });
+        if teleport {
+            // the teleport may not have been propagated to the global transform yet, so don't
+            // measure the next frame's motion from it
+            self.last_transform = None;
+            return self.teleport_physics(teleport);
+        }
+        if self.mode != SpinePhysicsMode::Update || self.inertia == 0. {
+            return self.physics();
+        }
+        // express the world motion in skeleton space, which is the entity's local space
+        let inverse = global_transform.affine().inverse();
+        let translation = inverse
+            .transform_vector3(global_transform.translation() - last_transform.translation())
+            * self.inertia;
+        let (scale, rotation, _) = global_transform.to_scale_rotation_translation();
+        let (_, last_rotation, _) = last_transform.to_scale_rotation_translation();
+        let mut degrees = (last_rotation.inverse() * rotation)
+            .to_euler(EulerRot::ZYX)
+            .0
+            .to_degrees()
+            * self.inertia;
+        if scale.x * scale.y < 0. {
+            degrees = -degrees;
+        }
+        unsafe {
+            if translation.x != 0. || translation.y != 0. {
+                spSkeleton_physicsTranslate(skeleton.c_ptr(), translation.x, translation.y);
+            }
+            if degrees != 0. {
+                spSkeleton_physicsRotate(skeleton.c_ptr(), 0., 0., degrees);
+            }
+        }
+        self.physics()
+    }
+
+    /// Called when the component is removed, to restore overridden wind and gravity.
+    pub(crate) fn restore_overrides(skeleton: &mut Skeleton) {
+        for mut physics_constraint in skeleton.physics_constraints_mut() {
+            let (wind, gravity) = {
+                let data = physics_constraint.data();
+                (data.wind(), data.gravity())
+            };
+            physics_constraint.set_wind(wind);
+            physics_constraint.set_gravity(gravity);
+        }
+    }
+
+    fn teleport_physics(&self, teleport: bool) -> Physics {
+        if teleport && self.mode != SpinePhysicsMode::None {
+            Physics::Reset
+        } else {
+            self.physics()
+        }
+    }
+}
+
+/// Marks a [`Spine`](`crate::Spine`) entity as teleported this frame, resetting its physics
+/// constraints to the current pose instead of simulating the jump.
+///
+/// The marker is removed automatically after the next animation update.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpineTeleport};
+/// fn respawn(mut commands: Commands, mut player: Single<(Entity, &mut Transform), With<Spine>>) {
+///     let (player_entity, player_transform) = &mut *player;
+///     player_transform.translation = Vec3::ZERO;
+///     commands.entity(*player_entity).insert(SpineTeleport);
+/// }
+/// ```
+///
+/// Teleports can also be detected automatically with [`SpinePhysics::teleport_distance`].
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpineTeleport;
@@ -0,0 +1,233 @@
+//! Posing skeletons at exact animation times, independent of their
+//! [`AnimationState`](`rusty_spine::AnimationState`).
+//!
+//! See [`Spine::pose_at`] and [`SpineScrub`].
+
+use std::ptr::null_mut;
+
+use bevy::prelude::*;
+use rusty_spine::{
+    c::{
+        spAnimation_apply, spMixBlend, SP_MIX_BLEND_ADD, SP_MIX_BLEND_FIRST, SP_MIX_BLEND_REPLACE,
+        SP_MIX_BLEND_SETUP, SP_MIX_DIRECTION_IN,
+    },
+    Physics, Skeleton, SpineError,
+};
+
+use crate::Spine;
+
+/// Controls how an animation posed with [`Spine::pose_at`] blends with the current pose.
+///
+/// See the [Spine API Reference](http://esotericsoftware.com/spine-api-reference#MixBlend).
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpineMixBlend {
+    /// Blend from the setup pose, ignoring the current pose.
+    #[default]
+    Setup,
+    /// Blend from the setup pose for properties which are keyed before the first frame, otherwise
+    /// from the current pose.
+    First,
+    /// Blend from the current pose.
+    Replace,
+    /// Add to the current pose.
+    Add,
+}
+
+impl SpineMixBlend {
+    fn c_mix_blend(self) -> spMixBlend {
+        match self {
+            SpineMixBlend::Setup => SP_MIX_BLEND_SETUP,
+            SpineMixBlend::First => SP_MIX_BLEND_FIRST,
+            SpineMixBlend::Replace => SP_MIX_BLEND_REPLACE,
+            SpineMixBlend::Add => SP_MIX_BLEND_ADD,
+        }
+    }
+}
+
+impl Spine {
+    /// Pose the skeleton at `time` (in seconds) of an animation, then update its world transform.
+    ///
+    /// The [`AnimationState`](`rusty_spine::AnimationState`) is not advanced or modified, and no
+    /// Spine events are fired. Properties not keyed by the animation keep their current pose, so
+    /// call [`Skeleton::set_to_setup_pose`] first for a clean pose.
+    ///
+    /// Meshes reflect the new pose if this is called before
+    /// [`SpineSystem::UpdateMeshes`](`crate::SpineSystem::UpdateMeshes`). However, a playing
+    /// animation overwrites it during the next
+    /// [`SpineSystem::UpdateAnimation`](`crate::SpineSystem::UpdateAnimation`), so use
+    /// [`SpineScrub`] to hold a pose across frames.
+    ///
+    /// ```
+    /// # use bevy::prelude::*;
+    /// # use bevy_spine::{prelude::*, SpineMixBlend};
+    /// fn thumbnail(mut spine_query: Query<&mut Spine>) {
+    ///     for mut spine in spine_query.iter_mut() {
+    ///         spine.skeleton.set_to_setup_pose();
+    ///         spine.pose_at("run", 0.25, SpineMixBlend::Setup).unwrap();
+    ///     }
+    /// }
+    /// ```
+    pub fn pose_at(
+        &mut self,
+        animation: &str,
+        time: f32,
+        mix_blend: SpineMixBlend,
+    ) -> Result<(), SpineError> {
+        apply_animation(&mut self.skeleton, animation, time, 1., mix_blend)?;
+        self.skeleton.update_world_transform(Physics::Pose);
+        Ok(())
+    }
+
+    /// Pose the skeleton as a blend between two animations, then update its world transform.
+    ///
+    /// An `alpha` of `0.` poses only `from`, while `1.` poses only `to`. See [`Spine::pose_at`].
+    pub fn pose_blended(
+        &mut self,
+        from: &str,
+        from_time: f32,
+        to: &str,
+        to_time: f32,
+        alpha: f32,
+    ) -> Result<(), SpineError> {
+        apply_animation(
+            &mut self.skeleton,
+            from,
+            from_time,
+            1.,
+            SpineMixBlend::Setup,
+        )?;
+        apply_animation(
+            &mut self.skeleton,
+            to,
+            to_time,
+            alpha,
+            SpineMixBlend::Replace,
+        )?;
+        self.skeleton.update_world_transform(Physics::Pose);
+        Ok(())
+    }
+}
+
+/// Applies a single animation of `skeleton`'s data to its bones at `time`.
+pub(crate) fn apply_animation(
+    skeleton: &mut Skeleton,
+    animation: &str,
+    time: f32,
+    alpha: f32,
+    mix_blend: SpineMixBlend,
+) -> Result<(), SpineError> {
+    let c_animation = skeleton
+        .data()
+        .find_animation(animation)
+        .ok_or_else(|| SpineError::NotFound {
+            what: "Animation".to_owned(),
+            name: animation.to_owned(),
+        })?
+        .c_ptr();
+    // SAFETY: the animation belongs to the skeleton's data, and no timelines fire events since
+    // the event list is null. Single animations can't be applied through rusty_spine.
+    unsafe {
+        spAnimation_apply(
+            c_animation,
+            skeleton.c_ptr(),
+            time,
+            time,
+            0,
+            null_mut(),
+            null_mut(),
+            alpha,
+            mix_blend.c_mix_blend(),
+            SP_MIX_DIRECTION_IN,
+        );
+    }
+    Ok(())
+}
+
+/// Holds a [`Spine`] at an exact animation time instead of playing its
+/// [`AnimationState`](`rusty_spine::AnimationState`), useful for editors and timelines.
+///
+/// While this component exists,
+/// [`SpineSystem::UpdateAnimation`](`crate::SpineSystem::UpdateAnimation`) poses the skeleton
+/// from its setup pose with [`Spine::pose_at`] (or [`Spine::pose_blended`]) instead of updating
+/// the animation state. Tracks are left untouched, so removing the component resumes playback
+/// where it left off.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpineScrub};
+/// fn scrub(mut scrub_query: Query<&mut SpineScrub>, time: Res<Time>) {
+///     for mut scrub in scrub_query.iter_mut() {
+///         scrub.time = (time.elapsed_secs() * 0.1) % 1.;
+///     }
+/// }
+///
+/// # fn doc(mut commands: Commands, spine_entity: Entity) {
+/// commands
+///     .entity(spine_entity)
+///     .insert(SpineScrub::new("walk", 0.).with_blend("run", 0., 0.5));
+/// # }
+/// ```
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct SpineScrub {
+    /// The animation to pose.
+    pub animation: String,
+    /// The time (in seconds) of `animation` to pose.
+    pub time: f32,
+    /// An optional second animation to blend over `animation`.
+    pub blend: Option<SpineScrubBlend>,
+}
+
+/// A second animation to blend in a [`SpineScrub`], see [`Spine::pose_blended`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SpineScrubBlend {
+    pub animation: String,
+    pub time: f32,
+    pub alpha: f32,
+}
+
+impl SpineScrub {
+    pub fn new(animation: &str, time: f32) -> Self {
+        Self {
+            animation: animation.to_owned(),
+            time,
+            blend: None,
+        }
+    }
+
+    pub fn with_blend(mut self, animation: &str, time: f32, alpha: f32) -> Self {
+        self.blend = Some(SpineScrubBlend {
+            animation: animation.to_owned(),
+            time,
+            alpha,
+        });
+        self
+    }
+
+    /// Pose the skeleton without updating its world transform, which happens later in
+    /// [`SpineSystem::UpdateAnimation`](`crate::SpineSystem::UpdateAnimation`).
+    pub(crate) fn apply(&self, spine: &mut Spine) {
+        let skeleton = &mut spine.skeleton;
+        skeleton.set_to_setup_pose();
+        let result = apply_animation(
+            skeleton,
+            &self.animation,
+            self.time,
+            1.,
+            SpineMixBlend::Setup,
+        )
+        .and_then(|_| match &self.blend {
+            Some(blend) => apply_animation(
+                skeleton,
+                &blend.animation,
+                blend.time,
+                blend.alpha,
+                SpineMixBlend::Replace,
+            ),
+            None => Ok(()),
+        });
+        if let Err(err) = result {
+            // scrubs are applied every frame, so don't flood the log
+            bevy::log::warn_once!("Failed to scrub Spine animation: {}", err);
+        }
+    }
+}
@@ -0,0 +1,565 @@
+//! CPU rasterization of Spine renderables, for rendering skeletons without a GPU.
+//!
+//! [`SpineRaster`] draws the renderables of a [`SkeletonController`](`crate::SkeletonController`)
+//! into an RGBA buffer, matching the output of the built-in 2D materials before tone mapping. This
+//! is useful for golden-image tests, thumbnails and build tools running on machines without a
+//! GPU.
+//!
+//! ```
+//! # use bevy::prelude::*;
+//! # use bevy_spine::{prelude::*, raster::{SpineRaster, SpineRasterTextures}};
+//! fn thumbnail(spine: &mut Spine, textures: &SpineRasterTextures) -> Image {
+//!     let renderables = spine.renderables();
+//!     let bounds = SpineRaster::bounds(&renderables).unwrap_or_default();
+//!     let mut raster = SpineRaster::new(128, 128).with_bounds(bounds, 4.);
+//!     raster.draw(&renderables, textures);
+//!     raster.to_image()
+//! }
+//! ```
+
+use std::collections::HashMap;
+
+use bevy::{
+    math::Affine2,
+    prelude::*,
+    render::{
+        render_asset::RenderAssetUsages,
+        render_resource::{
+            BlendComponent, BlendFactor, BlendOperation, Extent3d, TextureDimension, TextureFormat,
+        },
+    },
+};
+use rusty_spine::{
+    atlas::AtlasFilter,
+    c::c_void,
+    controller::{SkeletonCombinedRenderable, SkeletonRenderable},
+    BlendMode,
+};
+
+use crate::{materials::spine_blend_state, textures::SpineTexture};
+
+/// An RGBA buffer which Spine renderables can be drawn into on the CPU.
+///
+/// Pixels are stored in linear space, like a GPU render target, and blended with the same blend
+/// states as the built-in materials. Textures are sampled and tinted (including two-color tint)
+/// the same way as `spine.wgsl`. [`SpineMaterialEffects`](`crate::SpineMaterialEffects`) and the
+/// camera's tone mapping (applied in the shader if `TONEMAP_IN_SHADER` is set) are not applied.
+#[derive(Debug, Clone)]
+pub struct SpineRaster {
+    width: u32,
+    height: u32,
+    pixels: Vec<LinearRgba>,
+    transform: Affine2,
+}
+
+impl SpineRaster {
+    /// Creates a transparent raster, with the skeleton's origin in the center and one skeleton
+    /// unit per pixel.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![LinearRgba::NONE; width as usize * height as usize],
+            transform: Affine2::from_translation(Vec2::new(width as f32, height as f32) / 2.)
+                * Affine2::from_scale(Vec2::new(1., -1.)),
+        }
+    }
+
+    /// Sets the transform from skeleton space (y up) to pixel space (y down, origin in the top
+    /// left corner).
+    pub fn with_transform(mut self, transform: Affine2) -> Self {
+        self.transform = transform;
+        self
+    }
+
+    /// Fits `bounds` (in skeleton space) into the raster, keeping its aspect ratio and leaving
+    /// `padding` pixels on each side.
+    pub fn with_bounds(self, bounds: Rect, padding: f32) -> Self {
+        let available =
+            (Vec2::new(self.width as f32, self.height as f32) - padding * 2.).max(Vec2::ZERO);
+        let size = bounds.size();
+        let scale = if size.x > 0. && size.y > 0. {
+            (available / size).min_element()
+        } else {
+            1.
+        };
+        let transform =
+            Affine2::from_translation(Vec2::new(self.width as f32, self.height as f32) / 2.)
+                * Affine2::from_scale(Vec2::new(scale, -scale))
+                * Affine2::from_translation(-bounds.center());
+        self.with_transform(transform)
+    }
+
+    /// The bounds of the vertices of `renderables`, in skeleton space.
+    pub fn bounds(renderables: &[impl SpineRasterRenderable]) -> Option<Rect> {
+        renderables
+            .iter()
+            .flat_map(|renderable| renderable.vertices())
+            .map(|vertex| Vec2::from(*vertex))
+            .fold(None, |bounds: Option<Rect>, vertex| {
+                Some(match bounds {
+                    Some(bounds) => bounds.union_point(vertex),
+                    None => Rect::from_corners(vertex, vertex),
+                })
+            })
+    }
+
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// The linear color of a pixel, with color channels already multiplied by alpha by the
+    /// blend states.
+    pub fn pixel(&self, x: u32, y: u32) -> LinearRgba {
+        self.pixels[(y * self.width + x) as usize]
+    }
+
+    /// Fills the raster with a linear color.
+    pub fn clear(&mut self, color: LinearRgba) {
+        self.pixels.fill(color);
+    }
+
+    /// Draws renderables in order. Renderables with textures missing from `textures` are
+    /// skipped.
+    pub fn draw(
+        &mut self,
+        renderables: &[impl SpineRasterRenderable],
+        textures: &SpineRasterTextures,
+    ) {
+        for renderable in renderables {
+            let Some(texture) = renderable
+                .texture()
+                .and_then(|texture| textures.get(texture))
+            else {
+                continue;
+            };
+            let blend_state =
+                spine_blend_state(renderable.blend_mode(), renderable.premultiplied_alpha());
+            let vertices = renderable.vertices();
+            for triangle in 0..renderable.index_count() / 3 {
+                let mut indices = [0, 1, 2].map(|corner| renderable.index(triangle * 3 + corner));
+                let mut points =
+                    indices.map(|index| self.transform.transform_point2(vertices[index].into()));
+                let mut area = edge(points[0], points[1], points[2]);
+                if area == 0. || !area.is_finite() {
+                    continue;
+                }
+                // wind every triangle the same way, so that shared edges are only drawn once
+                if area < 0. {
+                    indices.swap(1, 2);
+                    points.swap(1, 2);
+                    area = -area;
+                }
+                let min = points[0]
+                    .min(points[1])
+                    .min(points[2])
+                    .floor()
+                    .max(Vec2::ZERO);
+                let max = points[0]
+                    .max(points[1])
+                    .max(points[2])
+                    .ceil()
+                    .min(Vec2::new(self.width as f32, self.height as f32));
+                for y in min.y as u32..max.y as u32 {
+                    for x in min.x as u32..max.x as u32 {
+                        let point = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+                        let weights = [
+                            (points[1], points[2]),
+                            (points[2], points[0]),
+                            (points[0], points[1]),
+                        ]
+                        .map(|(a, b)| (edge(a, b, point), owns_edge(a, b)));
+                        if weights
+                            .iter()
+                            .any(|(weight, owned)| *weight < 0. || (*weight == 0. && !owned))
+                        {
+                            continue;
+                        }
+                        let weights = weights.map(|(weight, _)| weight / area);
+                        let interpolate = |values: [[f32; 4]; 3]| {
+                            Vec4::from(values[0]) * weights[0]
+                                + Vec4::from(values[1]) * weights[1]
+                                + Vec4::from(values[2]) * weights[2]
+                        };
+                        let uv = interpolate(indices.map(|index| {
+                            let [u, v] = renderable.uvs()[index];
+                            [u, v, 0., 0.]
+                        }))
+                        .xy();
+                        let color = interpolate(indices.map(|index| renderable.color(index)));
+                        let dark_color =
+                            interpolate(indices.map(|index| renderable.dark_color(index)));
+                        let source = fragment(texture.sample(uv), color, dark_color);
+                        let pixel = &mut self.pixels[(y * self.width + x) as usize];
+                        *pixel = blend(&blend_state.color, &blend_state.alpha, source, *pixel);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The pixels as 8-bit sRGB, as a GPU would store them in an sRGB render target.
+    pub fn to_rgba8(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|pixel| Srgba::from(*pixel).to_u8_array())
+            .collect()
+    }
+
+    /// The pixels as 8-bit sRGB with unpremultiplied alpha, as expected by image files.
+    pub fn to_unpremultiplied_rgba8(&self) -> Vec<u8> {
+        self.pixels
+            .iter()
+            .flat_map(|pixel| {
+                let pixel = if pixel.alpha > 0. {
+                    LinearRgba::new(
+                        pixel.red / pixel.alpha,
+                        pixel.green / pixel.alpha,
+                        pixel.blue / pixel.alpha,
+                        pixel.alpha,
+                    )
+                } else {
+                    LinearRgba::NONE
+                };
+                Srgba::from(pixel).to_u8_array()
+            })
+            .collect()
+    }
+
+    /// Converts the raster to an unpremultiplied [`Image`], which can be drawn by Bevy or saved.
+    pub fn to_image(&self) -> Image {
+        Image::new(
+            Extent3d {
+                width: self.width,
+                height: self.height,
+                depth_or_array_layers: 1,
+            },
+            TextureDimension::D2,
+            self.to_unpremultiplied_rgba8(),
+            TextureFormat::Rgba8UnormSrgb,
+            RenderAssetUsages::default(),
+        )
+    }
+}
+
+/// Twice the signed area of the triangle `a`, `b`, `point`.
+fn edge(a: Vec2, b: Vec2, point: Vec2) -> f32 {
+    (b - a).perp_dot(point - a)
+}
+
+/// Pixels exactly on an edge belong to only one of the two triangles sharing it.
+fn owns_edge(a: Vec2, b: Vec2) -> bool {
+    let direction = b - a;
+    direction.y > 0. || (direction.y == 0. && direction.x < 0.)
+}
+
+/// The fragment shader of `spine.wgsl`.
+fn fragment(texture: Vec4, color: Vec4, dark_color: Vec4) -> LinearRgba {
+    let rgb = ((texture.w - 1.) * dark_color.w + 1. - texture.xyz()) * dark_color.xyz()
+        + texture.xyz() * color.xyz();
+    LinearRgba::new(rgb.x, rgb.y, rgb.z, texture.w * color.w)
+}
+
+fn blend(
+    color: &BlendComponent,
+    alpha: &BlendComponent,
+    source: LinearRgba,
+    destination: LinearRgba,
+) -> LinearRgba {
+    let source = source.to_f32_array();
+    let destination = destination.to_f32_array();
+    let channel = |component: &BlendComponent, channel: usize| {
+        let factor = |factor: BlendFactor| match factor {
+            BlendFactor::Zero => 0.,
+            BlendFactor::Src => source[channel],
+            BlendFactor::OneMinusSrc => 1. - source[channel],
+            BlendFactor::SrcAlpha => source[3],
+            BlendFactor::OneMinusSrcAlpha => 1. - source[3],
+            BlendFactor::Dst => destination[channel],
+            BlendFactor::OneMinusDst => 1. - destination[channel],
+            BlendFactor::DstAlpha => destination[3],
+            BlendFactor::OneMinusDstAlpha => 1. - destination[3],
+            _ => 1.,
+        };
+        let source = source[channel] * factor(component.src_factor);
+        let destination = destination[channel] * factor(component.dst_factor);
+        let value = match component.operation {
+            BlendOperation::Add => source + destination,
+            BlendOperation::Subtract => source - destination,
+            BlendOperation::ReverseSubtract => destination - source,
+            BlendOperation::Min => source.min(destination),
+            BlendOperation::Max => source.max(destination),
+        };
+        value.clamp(0., 1.)
+    };
+    LinearRgba::new(
+        channel(color, 0),
+        channel(color, 1),
+        channel(color, 2),
+        channel(alpha, 3),
+    )
+}
+
+/// A texture which can be sampled by [`SpineRaster`].
+#[derive(Debug, Clone)]
+pub struct SpineRasterTexture {
+    width: u32,
+    height: u32,
+    texels: Vec<Vec4>,
+    filter: AtlasFilter,
+}
+
+impl SpineRasterTexture {
+    /// Creates a texture from 8-bit sRGB data, such as an atlas page exported by Spine.
+    ///
+    /// Premultiplied alpha pages are converted the same way [`SpinePlugin`](`crate::SpinePlugin`)
+    /// converts them for the GPU.
+    pub fn new(width: u32, height: u32, rgba: &[u8], premultiplied_alpha: bool) -> Self {
+        let texels = rgba
+            .chunks_exact(4)
+            .map(|texel| {
+                let mut srgba = Srgba::rgba_u8(texel[0], texel[1], texel[2], texel[3]);
+                if premultiplied_alpha {
+                    if srgba.alpha == 0. {
+                        return Vec4::ZERO;
+                    }
+                    srgba.red /= srgba.alpha;
+                    srgba.green /= srgba.alpha;
+                    srgba.blue /= srgba.alpha;
+                }
+                let linear = LinearRgba::from(srgba);
+                if premultiplied_alpha {
+                    Vec4::new(
+                        linear.red * linear.alpha,
+                        linear.green * linear.alpha,
+                        linear.blue * linear.alpha,
+                        linear.alpha,
+                    )
+                } else {
+                    Vec4::from(linear.to_f32_array())
+                }
+            })
+            .collect();
+        Self {
+            width,
+            height,
+            texels,
+            filter: AtlasFilter::Linear,
+        }
+    }
+
+    /// Creates a texture from an [`Image`] as the GPU would sample it, such as an atlas page
+    /// which [`SpinePlugin`](`crate::SpinePlugin`) has already converted.
+    ///
+    /// Returns `None` for formats other than `Rgba8UnormSrgb` and `Rgba8Unorm`.
+    pub fn from_image(image: &Image) -> Option<Self> {
+        let format = image.texture_descriptor.format;
+        if !matches!(
+            format,
+            TextureFormat::Rgba8UnormSrgb | TextureFormat::Rgba8Unorm
+        ) {
+            return None;
+        }
+        let size = image.size();
+        let texels = image
+            .data
+            .chunks_exact(4)
+            .map(|texel| {
+                if format == TextureFormat::Rgba8UnormSrgb {
+                    let linear =
+                        LinearRgba::from(Srgba::rgba_u8(texel[0], texel[1], texel[2], texel[3]));
+                    Vec4::from(linear.to_f32_array())
+                } else {
+                    Vec4::new(
+                        texel[0] as f32,
+                        texel[1] as f32,
+                        texel[2] as f32,
+                        texel[3] as f32,
+                    ) / 255.
+                }
+            })
+            .collect();
+        Some(Self {
+            width: size.x,
+            height: size.y,
+            texels,
+            filter: AtlasFilter::Linear,
+        })
+    }
+
+    /// Sets how the texture is filtered (default: `AtlasFilter::Linear`). Filters other than
+    /// `Nearest` are sampled linearly.
+    pub fn with_filter(mut self, filter: AtlasFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    fn texel(&self, x: i64, y: i64) -> Vec4 {
+        let x = x.clamp(0, self.width as i64 - 1) as usize;
+        let y = y.clamp(0, self.height as i64 - 1) as usize;
+        self.texels[y * self.width as usize + x]
+    }
+
+    /// Samples the texture, clamping to its edges.
+    fn sample(&self, uv: Vec2) -> Vec4 {
+        if self.width == 0 || self.height == 0 {
+            return Vec4::ZERO;
+        }
+        let position = uv * Vec2::new(self.width as f32, self.height as f32);
+        if matches!(self.filter, AtlasFilter::Nearest) {
+            return self.texel(position.x.floor() as i64, position.y.floor() as i64);
+        }
+        let position = position - 0.5;
+        let (x, y) = (position.x.floor(), position.y.floor());
+        let (tx, ty) = (position.x - x, position.y - y);
+        let (x, y) = (x as i64, y as i64);
+        let top = self.texel(x, y).lerp(self.texel(x + 1, y), tx);
+        let bottom = self.texel(x, y + 1).lerp(self.texel(x + 1, y + 1), tx);
+        top.lerp(bottom, ty)
+    }
+}
+
+/// The textures available to [`SpineRaster`], keyed by [`SpineTexture`].
+#[derive(Default, Debug, Clone)]
+pub struct SpineRasterTextures {
+    pages: HashMap<String, SpineRasterTexture>,
+    images: HashMap<AssetId<Image>, SpineRasterTexture>,
+}
+
+impl SpineRasterTextures {
+    /// Adds the texture of an atlas page, by its asset path (see [`SpineTexture::Path`]).
+    pub fn insert_page(&mut self, path: impl Into<String>, texture: SpineRasterTexture) {
+        self.pages.insert(path.into(), texture);
+    }
+
+    /// Adds the texture of an image attachment (see [`SpineTexture::Image`]).
+    pub fn insert_image(&mut self, image: impl Into<AssetId<Image>>, texture: SpineRasterTexture) {
+        self.images.insert(image.into(), texture);
+    }
+
+    pub fn get(&self, texture: &SpineTexture) -> Option<&SpineRasterTexture> {
+        match texture {
+            SpineTexture::Path(path) => self.pages.get(path),
+            SpineTexture::Image(image) => self.images.get(&image.id()),
+        }
+    }
+}
+
+/// Renderables which can be drawn by [`SpineRaster`].
+///
+/// Implemented for the renderables returned by [`Spine::renderables`](`crate::Spine::renderables`)
+/// and
+/// [`SkeletonController::combined_renderables`](`crate::SkeletonController::combined_renderables`).
+pub trait SpineRasterRenderable {
+    fn vertices(&self) -> &[[f32; 2]];
+
+    fn uvs(&self) -> &[[f32; 2]];
+
+    /// The number of indices, three per triangle.
+    fn index_count(&self) -> usize;
+
+    fn index(&self, index: usize) -> usize;
+
+    fn color(&self, vertex: usize) -> [f32; 4];
+
+    fn dark_color(&self, vertex: usize) -> [f32; 4];
+
+    fn blend_mode(&self) -> BlendMode;
+
+    fn premultiplied_alpha(&self) -> bool;
+
+    fn texture(&self) -> Option<&SpineTexture>;
+}
+
+/// Reads the [`SpineTexture`] which [`SpinePlugin`](`crate::SpinePlugin`) stores as the renderer
+/// object of atlas pages and image attachments.
+fn renderer_object_texture<'a>(renderer_object: Option<*const c_void>) -> Option<&'a SpineTexture> {
+    renderer_object
+        .filter(|renderer_object| !renderer_object.is_null())
+        .map(|renderer_object| unsafe { &*(renderer_object as *const SpineTexture) })
+}
+
+impl SpineRasterRenderable for SkeletonRenderable {
+    fn vertices(&self) -> &[[f32; 2]] {
+        &self.vertices
+    }
+
+    fn uvs(&self) -> &[[f32; 2]] {
+        &self.uvs
+    }
+
+    fn index_count(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn index(&self, index: usize) -> usize {
+        self.indices[index] as usize
+    }
+
+    fn color(&self, _vertex: usize) -> [f32; 4] {
+        [self.color.r, self.color.g, self.color.b, self.color.a]
+    }
+
+    fn dark_color(&self, _vertex: usize) -> [f32; 4] {
+        [
+            self.dark_color.r,
+            self.dark_color.g,
+            self.dark_color.b,
+            self.dark_color.a,
+        ]
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn premultiplied_alpha(&self) -> bool {
+        self.premultiplied_alpha
+    }
+
+    fn texture(&self) -> Option<&SpineTexture> {
+        renderer_object_texture(self.attachment_renderer_object)
+    }
+}
+
+impl SpineRasterRenderable for SkeletonCombinedRenderable {
+    fn vertices(&self) -> &[[f32; 2]] {
+        &self.vertices
+    }
+
+    fn uvs(&self) -> &[[f32; 2]] {
+        &self.uvs
+    }
+
+    fn index_count(&self) -> usize {
+        self.indices.len()
+    }
+
+    fn index(&self, index: usize) -> usize {
+        self.indices[index] as usize
+    }
+
+    fn color(&self, vertex: usize) -> [f32; 4] {
+        self.colors[vertex]
+    }
+
+    fn dark_color(&self, vertex: usize) -> [f32; 4] {
+        self.dark_colors[vertex]
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn premultiplied_alpha(&self) -> bool {
+        self.premultiplied_alpha
+    }
+
+    fn texture(&self) -> Option<&SpineTexture> {
+        renderer_object_texture(self.attachment_renderer_object)
+    }
+}
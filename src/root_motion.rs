@@ -0,0 +1,289 @@
+//! Root motion extraction from Spine animations.
+//!
+//! See [`SpineRootMotion`].
+
+use std::ptr::null_mut;
+
+use bevy::prelude::*;
+use rusty_spine::{
+    c::{
+        c_int, spRotateTimeline, spTimeline_apply, spTranslateTimeline, spTranslateXTimeline,
+        spTranslateYTimeline, SP_MIX_BLEND_SETUP, SP_MIX_DIRECTION_IN, SP_TIMELINE_ROTATE,
+        SP_TIMELINE_TRANSLATE, SP_TIMELINE_TRANSLATEX, SP_TIMELINE_TRANSLATEY,
+    },
+    Animation, Skeleton,
+};
+
+use crate::{SkeletonController, SpineTrackEntryId};
+
+/// Extracts the motion of a bone from the animations playing on track 0, moving the entity
+/// instead of the bone.
+///
+/// Every frame in [`SpineSystem::UpdateAnimation`](`crate::SpineSystem::UpdateAnimation`), the
+/// bone's local translation (and optionally rotation) change is measured after animations are
+/// applied, and the bone is held at its setup pose on the extracted axes. The change is then
+/// converted to the [`Spine`](`crate::Spine`) entity's local space and added to its
+/// [`Transform`], and written to [`RootMotionDelta`] if the entity has one.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, RootMotionDelta, SpineRootMotion};
+/// # fn doc(mut commands: Commands) {
+/// commands.spawn((
+///     SpineBundle {
+///         // ..
+///         ..Default::default()
+///     },
+///     // move the entity horizontally, leaving vertical motion in the animation
+///     SpineRootMotion::new("root").with_y(false),
+/// ));
+///
+/// // or let a character controller decide how to move
+/// commands.spawn((
+///     SpineBundle {
+///         // ..
+///         ..Default::default()
+///     },
+///     SpineRootMotion::new("root").with_apply_to_transform(false),
+///     RootMotionDelta::default(),
+/// ));
+/// # }
+/// ```
+///
+/// Looping animations carry their motion across the loop. Motion is reset whenever a new
+/// animation starts on track 0 without mixing from a previous one.
+#[derive(Component, Debug, Clone)]
+pub struct SpineRootMotion {
+    /// The name of the bone to extract motion from.
+    pub bone: String,
+    /// Indicates if motion along the bone's x axis is extracted (default: `true`).
+    pub x: bool,
+    /// Indicates if motion along the bone's y axis is extracted (default: `true`).
+    pub y: bool,
+    /// Indicates if the bone's rotation is extracted (default: `false`).
+    pub rotation: bool,
+    /// Indicates if the motion is added to this entity's [`Transform`] (default: `true`).
+    pub apply_to_transform: bool,
+    state: RootMotionState,
+}
+
+#[derive(Debug, Default, Clone)]
+struct RootMotionState {
+    entry: Option<SpineTrackEntryId>,
+    animation_time: f32,
+    last: Option<[f32; 3]>,
+    wrap: [f32; 3],
+}
+
+impl SpineRootMotion {
+    pub fn new(bone: &str) -> Self {
+        Self {
+            bone: bone.to_owned(),
+            x: true,
+            y: true,
+            rotation: false,
+            apply_to_transform: true,
+            state: RootMotionState::default(),
+        }
+    }
+
+    pub fn with_x(mut self, x: bool) -> Self {
+        self.x = x;
+        self
+    }
+
+    pub fn with_y(mut self, y: bool) -> Self {
+        self.y = y;
+        self
+    }
+
+    pub fn with_rotation(mut self, rotation: bool) -> Self {
+        self.rotation = rotation;
+        self
+    }
+
+    pub fn with_apply_to_transform(mut self, apply_to_transform: bool) -> Self {
+        self.apply_to_transform = apply_to_transform;
+        self
+    }
+
+    /// Called after [`AnimationState::update`](`rusty_spine::AnimationState::update`) but before
+    /// the animation state is applied to the skeleton.
+    pub(crate) fn before_apply(&mut self, controller: &mut SkeletonController) {
+        self.state.wrap = [0.; 3];
+        let Some(track_entry) = controller.animation_state.get_current(0) else {
+            self.state.entry = None;
+            self.state.last = None;
+            return;
+        };
+        let id = SpineTrackEntryId::of(&track_entry);
+        let animation_time = track_entry.animation_time();
+        if self.state.entry == Some(id) {
+            if track_entry.looping() && animation_time < self.state.animation_time {
+                // the animation looped since last frame, so carry the motion from the end of the
+                // loop over to its start
+                let animation = track_entry.animation();
+                let end = sample_bone(
+                    &animation,
+                    &mut controller.skeleton,
+                    &self.bone,
+                    track_entry.animation_end(),
+                );
+                let start = sample_bone(
+                    &animation,
+                    &mut controller.skeleton,
+                    &self.bone,
+                    track_entry.animation_start(),
+                );
+                if let (Some(end), Some(start)) = (end, start) {
+                    self.state.wrap = [end[0] - start[0], end[1] - start[1], end[2] - start[2]];
+                }
+            }
+        } else if track_entry.mixing_from().is_none() {
+            self.state.last = None;
+        }
+        self.state.entry = Some(id);
+        self.state.animation_time = animation_time;
+    }
+
+    /// Called after the animation state is applied to the skeleton. Returns the extracted
+    /// translation and rotation (in degrees) in skeleton space.
+    pub(crate) fn after_apply(&mut self, controller: &mut SkeletonController) -> (Vec2, f32) {
+        let skeleton = &mut controller.skeleton;
+        let (skeleton_scale_x, skeleton_scale_y) = (skeleton.scale_x(), skeleton.scale_y());
+        update_ancestor_world_transforms(skeleton, &self.bone);
+        let Some(mut bone) = skeleton.find_bone_mut(&self.bone) else {
+            return (Vec2::ZERO, 0.);
+        };
+        let current = [bone.x(), bone.y(), bone.rotation()];
+        let last = self.state.last.replace(current).unwrap_or(current);
+        let mut delta = [
+            current[0] - last[0] + self.state.wrap[0],
+            current[1] - last[1] + self.state.wrap[1],
+            current[2] - last[2] + self.state.wrap[2],
+        ];
+        let bone_data = bone.data();
+        let setup = [bone_data.x(), bone_data.y(), bone_data.rotation()];
+        if self.x {
+            bone.set_x(setup[0]);
+        } else {
+            delta[0] = 0.;
+        }
+        if self.y {
+            bone.set_y(setup[1]);
+        } else {
+            delta[1] = 0.;
+        }
+        if self.rotation {
+            bone.set_rotation(setup[2]);
+        } else {
+            delta[2] = 0.;
+        }
+        // local translations are in the parent bone's space (or skeleton space for the root), as
+        // posed by this frame's animations
+        let (a, b, c, d) = match bone.parent() {
+            Some(parent) => (parent.a(), parent.b(), parent.c(), parent.d()),
+            None => (skeleton_scale_x, 0., 0., skeleton_scale_y),
+        };
+        let translation = Vec2::new(a * delta[0] + b * delta[1], c * delta[0] + d * delta[1]);
+        let rotation = if a * d - b * c < 0. {
+            -delta[2]
+        } else {
+            delta[2]
+        };
+        (translation, rotation)
+    }
+}
+
+/// The motion extracted by [`SpineRootMotion`] during the last animation update, in the
+/// [`Spine`](`crate::Spine`) entity's local space.
+#[derive(Component, Default, Debug, Clone, Copy, PartialEq)]
+pub struct RootMotionDelta {
+    pub translation: Vec2,
+    /// Counter clockwise rotation in radians.
+    pub rotation: f32,
+}
+
+/// Updates the world transforms of the ancestors of `bone` from their current local transforms,
+/// so that the parent's world transform reflects this frame's animations rather than the last
+/// frame's. Constraints are not applied, since the skeleton's world transform is updated later.
+fn update_ancestor_world_transforms(skeleton: &mut Skeleton, bone: &str) {
+    let mut ancestors = vec![];
+    let mut parent = skeleton
+        .find_bone(bone)
+        .and_then(|bone| bone.parent().map(|parent| parent.data().index()));
+    while let Some(index) = parent {
+        ancestors.push(index);
+        parent = skeleton
+            .bone_at_index(index)
+            .and_then(|bone| bone.parent().map(|parent| parent.data().index()));
+    }
+    for index in ancestors.into_iter().rev() {
+        if let Some(mut ancestor) = skeleton.bone_at_index_mut(index) {
+            ancestor.update_world_transform();
+        }
+    }
+}
+
+/// Samples the translate and rotate timelines of `bone` at `time` in `animation`, returning the
+/// bone's local translation and rotation.
+///
+/// Only the bone's own timelines are applied, and its local transform is restored afterwards, so
+/// the rest of the skeleton is left as it is before the animation state is applied.
+fn sample_bone(
+    animation: &Animation,
+    skeleton: &mut Skeleton,
+    bone: &str,
+    time: f32,
+) -> Option<[f32; 3]> {
+    let (bone_index, current) = {
+        let mut bone = skeleton.find_bone_mut(bone)?;
+        let current = [bone.x(), bone.y(), bone.rotation()];
+        let (index, setup) = {
+            let bone_data = bone.data();
+            (
+                bone_data.index() as c_int,
+                [bone_data.x(), bone_data.y(), bone_data.rotation()],
+            )
+        };
+        bone.set_x(setup[0]);
+        bone.set_y(setup[1]);
+        bone.set_rotation(setup[2]);
+        (index, current)
+    };
+    // SAFETY: the animation belongs to the skeleton's data, so its timelines and bone indices are
+    // valid for the skeleton. The timeline types are checked before casting, and no timelines fire
+    // events since the event list is null.
+    unsafe {
+        let timelines = &*(*animation.c_ptr()).timelines;
+        for timeline_index in 0..timelines.size as usize {
+            let timeline = *timelines.items.add(timeline_index);
+            let timeline_bone_index = match (*timeline).type_0 {
+                SP_TIMELINE_ROTATE => (*timeline.cast::<spRotateTimeline>()).boneIndex,
+                SP_TIMELINE_TRANSLATE => (*timeline.cast::<spTranslateTimeline>()).boneIndex,
+                SP_TIMELINE_TRANSLATEX => (*timeline.cast::<spTranslateXTimeline>()).boneIndex,
+                SP_TIMELINE_TRANSLATEY => (*timeline.cast::<spTranslateYTimeline>()).boneIndex,
+                _ => continue,
+            };
+            if timeline_bone_index == bone_index {
+                spTimeline_apply(
+                    timeline,
+                    skeleton.c_ptr(),
+                    time,
+                    time,
+                    null_mut(),
+                    null_mut(),
+                    1.,
+                    SP_MIX_BLEND_SETUP,
+                    SP_MIX_DIRECTION_IN,
+                );
+            }
+        }
+    }
+    let mut bone = skeleton.bone_at_index_mut(bone_index as usize)?;
+    let sampled = [bone.x(), bone.y(), bone.rotation()];
+    bone.set_x(current[0]);
+    bone.set_y(current[1]);
+    bone.set_rotation(current[2]);
+    Some(sampled)
+}
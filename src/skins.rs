@@ -0,0 +1,90 @@
+//! Composing skeleton skins from multiple Spine skins.
+//!
+//! See [`SpineSkins`].
+
+use bevy::prelude::*;
+use rusty_spine::Skin;
+
+use crate::Spine;
+
+/// The skins to combine into the skin of a [`Spine`], useful for character customization.
+///
+/// Whenever this component changes (or the [`Spine`] finishes loading), the named skins are added
+/// to a new combined skin which replaces the skeleton's current skin, and the skeleton's slots are
+/// reset to their setup pose so every slot shows its setup attachment from the new skin. Bones are
+/// left as they are, so skins can be changed mid-animation. Skins which do not exist in the
+/// skeleton are skipped with a warning.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpineSkins};
+/// # fn doc(mut commands: Commands) {
+/// commands.spawn((
+///     SpineBundle {
+///         // ..
+///         ..Default::default()
+///     },
+///     SpineSkins::new(["skin-base", "nose/short", "eyes/violet", "hair/brown"]),
+/// ));
+/// # }
+///
+/// fn change_hair(mut skins_query: Query<&mut SpineSkins>) {
+///     for mut skins in skins_query.iter_mut() {
+///         skins.0.retain(|skin| !skin.starts_with("hair/"));
+///         skins.0.push("hair/blue".to_owned());
+///     }
+/// }
+/// ```
+#[derive(Component, Default, Debug, Clone, PartialEq, Eq)]
+pub struct SpineSkins(pub Vec<String>);
+
+impl SpineSkins {
+    pub fn new<T: Into<String>>(skins: impl IntoIterator<Item = T>) -> Self {
+        Self(skins.into_iter().map(Into::into).collect())
+    }
+}
+
+/// The combined skin of a [`Spine`] with [`SpineSkins`], which must live as long as the skeleton
+/// uses it.
+#[derive(Component)]
+pub(crate) struct SpineCombinedSkin(Skin);
+
+pub(crate) fn spine_update_skins(
+    mut commands: Commands,
+    mut spine_query: Query<(
+        Entity,
+        &mut Spine,
+        Ref<SpineSkins>,
+        Option<&mut SpineCombinedSkin>,
+    )>,
+) {
+    for (entity, mut spine, skins, combined_skin_component) in spine_query.iter_mut() {
+        if !spine.is_added() && !skins.is_changed() {
+            continue;
+        }
+        let skeleton = &mut spine.skeleton;
+        let mut combined_skin = Skin::new("bevy_spine_combined");
+        for name in skins.0.iter() {
+            match skeleton.data().find_skin(name) {
+                Some(skin) => unsafe {
+                    // the skin comes from this skeleton's data
+                    combined_skin.add_skin(&skin);
+                },
+                None => warn!("Spine skin {:?} does not exist in skeleton", name),
+            }
+        }
+        unsafe {
+            // the combined skin is kept alive in `SpineCombinedSkin` until it is replaced
+            skeleton.set_skin(&combined_skin);
+        }
+        skeleton.set_slots_to_setup_pose();
+        match combined_skin_component {
+            Some(mut combined_skin_component) => combined_skin_component.0 = combined_skin,
+            None => {
+                commands
+                    .entity(entity)
+                    .insert(SpineCombinedSkin(combined_skin));
+            }
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Drawing other entities between Spine slots.
+//!
+//! See [`SpineSlotSocket`].
+
+use bevy::prelude::*;
+use rusty_spine::Skeleton;
+
+/// Places an entity right after a slot in its [`Spine`](`crate::Spine`)'s draw order, so that
+/// sprites, particles or any other meshes can be drawn between the skeleton's slots.
+///
+/// The entity must be a child of the [`Spine`](`crate::Spine`) entity. Every frame its z
+/// position is set between the meshes drawn before and after the slot, following draw order
+/// changes from animations. By default, the entity also follows the slot's bone.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpineSlotSocket};
+/// # fn doc(mut commands: Commands, spine_entity: Entity, sword: Handle<Image>) {
+/// commands.entity(spine_entity).with_children(|parent| {
+///     parent.spawn((Sprite::from_image(sword), SpineSlotSocket::new("front-upper-arm")));
+/// });
+/// # }
+/// ```
+///
+/// To keep slots drawn before and after the socket in separate meshes, skeletons with sockets
+/// build their meshes in multiple batches, one for each socketed slot. Clipping attachments do not
+/// clip slots on the other side of a socket.
+#[derive(Component, Debug, Clone, PartialEq, Eq)]
+pub struct SpineSlotSocket {
+    /// The name of the slot to draw after.
+    pub slot: String,
+    /// If `true`, the entity's translation, rotation and scale follow the slot's bone (default:
+    /// `true`). Otherwise, only its z position is changed.
+    pub follow_bone: bool,
+}
+
+impl SpineSlotSocket {
+    pub fn new(slot: &str) -> Self {
+        Self {
+            slot: slot.to_owned(),
+            follow_bone: true,
+        }
+    }
+
+    pub fn with_follow_bone(mut self, follow_bone: bool) -> Self {
+        self.follow_bone = follow_bone;
+        self
+    }
+}
+
+/// Groups the sockets by the draw order index of their slot, sorted in draw order.
+pub(crate) fn socket_splits<'a>(
+    skeleton: &Skeleton,
+    sockets: impl Iterator<Item = (Entity, &'a SpineSlotSocket)>,
+) -> Vec<(usize, Vec<Entity>)> {
+    let mut splits: Vec<(usize, Vec<Entity>)> = vec![];
+    for (socket_entity, socket) in sockets {
+        let Some(draw_index) = skeleton
+            .draw_order()
+            .position(|slot| slot.data().name() == socket.slot)
+        else {
+            continue;
+        };
+        match splits.iter_mut().find(|(index, _)| *index == draw_index) {
+            Some((_, entities)) => entities.push(socket_entity),
+            None => splits.push((draw_index, vec![socket_entity])),
+        }
+    }
+    splits.sort_by_key(|(draw_index, _)| *draw_index);
+    splits
+}
+
+/// Moves a socket entity to the slot's bone, if it follows it, and to the given z position.
+pub(crate) fn update_socket_transform(
+    skeleton: &Skeleton,
+    socket: &SpineSlotSocket,
+    transform: &mut Transform,
+    z: f32,
+) {
+    transform.translation.z = z;
+    if !socket.follow_bone {
+        return;
+    }
+    let Some(slot) = skeleton.find_slot(&socket.slot) else {
+        return;
+    };
+    let bone = slot.bone();
+    transform.translation.x = bone.world_x();
+    transform.translation.y = bone.world_y();
+    transform.rotation = Quat::from_axis_angle(Vec3::Z, bone.world_rotation_x().to_radians());
+    transform.scale.x = bone.world_scale_x();
+    transform.scale.y = bone.world_scale_y();
+}
@@ -0,0 +1,38 @@
+//! Hiding slots from rendering.
+//!
+//! See [`SpineSlotVisibility`].
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+
+/// Hides slots of a [`Spine`](`crate::Spine`) from its meshes, keyed by slot name.
+///
+/// Hidden slots are skipped while meshes are built, without changing their attachments, so
+/// animation timelines keep working and the slot shows its current attachment again as soon as
+/// it's made visible. Slots not in the map, or missing from the skeleton, are visible.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpineSlotVisibility};
+/// fn holster(mut visibility_query: Query<&mut SpineSlotVisibility>) {
+///     for mut visibility in visibility_query.iter_mut() {
+///         visibility.0.insert("gun".to_owned(), false);
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+pub struct SpineSlotVisibility(pub HashMap<String, bool>);
+
+impl SpineSlotVisibility {
+    pub fn with(mut self, slot: &str, visible: bool) -> Self {
+        self.0.insert(slot.to_owned(), visible);
+        self
+    }
+
+    /// Returns `false` if the slot is hidden.
+    pub fn is_visible(&self, slot: &str) -> bool {
+        self.0.get(slot).copied().unwrap_or(true)
+    }
+}
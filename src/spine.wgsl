@@ -2,6 +2,7 @@
     mesh2d_functions as mesh_functions,
     mesh2d_view_bindings::view,
 }
+#import bevy_spine::effects::{SpineEffects, apply_effects}
 
 #ifdef TONEMAP_IN_SHADER
 #import bevy_core_pipeline::tonemapping
@@ -10,8 +11,10 @@
 struct Vertex {
     @builtin(instance_index) instance_index: u32,
     @location(0) position: vec3<f32>,
-    @location(1) normal: vec3<f32>,
     @location(2) uv: vec2<f32>,
+#ifdef SPINE_NORMAL_MAP
+    @location(3) tangent: vec4<f32>,
+#endif
     @location(4) color: vec4<f32>,
     @location(10) dark_color: vec4<f32>,
 };
@@ -19,8 +22,13 @@ struct Vertex {
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
     @location(0) world_position: vec4<f32>,
+#ifdef SPINE_LIT
     @location(1) world_normal: vec3<f32>,
+#endif
     @location(2) uv: vec2<f32>,
+#ifdef SPINE_NORMAL_MAP
+    @location(3) world_tangent: vec4<f32>,
+#endif
     @location(4) color: vec4<f32>,
     @location(10) dark_color: vec4<f32>,
 };
@@ -35,7 +43,13 @@ fn vertex(vertex: Vertex) -> VertexOutput {
         vec4<f32>(vertex.position, 1.0)
     );
     out.position = mesh_functions::mesh2d_position_world_to_clip(out.world_position);
-    out.world_normal = mesh_functions::mesh2d_normal_local_to_world(vertex.normal, vertex.instance_index);
+#ifdef SPINE_LIT
+    // spine meshes are flat, facing towards +z
+    out.world_normal = mesh_functions::mesh2d_normal_local_to_world(vec3<f32>(0.0, 0.0, 1.0), vertex.instance_index);
+#endif
+#ifdef SPINE_NORMAL_MAP
+    out.world_tangent = mesh_functions::mesh2d_tangent_local_to_world(model, vertex.tangent);
+#endif
     out.color = vertex.color;
     out.dark_color = vertex.dark_color;
     return out;
@@ -45,6 +59,24 @@ fn vertex(vertex: Vertex) -> VertexOutput {
 var texture: texture_2d<f32>;
 @group(2) @binding(1)
 var texture_sampler: sampler;
+@group(2) @binding(2)
+var<uniform> effects: SpineEffects;
+@group(2) @binding(3)
+var dissolve_noise: texture_2d<f32>;
+@group(2) @binding(4)
+var dissolve_noise_sampler: sampler;
+@group(2) @binding(5)
+var<uniform> light: SpineLight2d;
+@group(2) @binding(6)
+var normal_map: texture_2d<f32>;
+@group(2) @binding(7)
+var normal_map_sampler: sampler;
+
+struct SpineLight2d {
+    ambient_color: vec4<f32>,
+    color: vec4<f32>,
+    direction: vec3<f32>,
+};
 
 @fragment
 fn fragment(
@@ -55,6 +87,21 @@ fn fragment(
         ((tex_color.a - 1.0) * input.dark_color.a + 1.0 - tex_color.rgb) * input.dark_color.rgb + tex_color.rgb * input.color.rgb,
         tex_color.a * input.color.a,
     );
+#ifdef SPINE_LIT
+    var normal = normalize(input.world_normal);
+#ifdef SPINE_NORMAL_MAP
+    // same tangent space as bevy_pbr's normal maps
+    let tangent = normalize(input.world_tangent.xyz);
+    let bitangent = sign(input.world_tangent.w) * cross(normal, tangent);
+    let normal_sample = textureSample(normal_map, normal_map_sampler, input.uv).rgb * 2.0 - 1.0;
+    normal = normalize(normal_sample.x * tangent + normal_sample.y * bitangent + normal_sample.z * normal);
+#endif
+    let diffuse = max(dot(normal, -light.direction), 0.0);
+    color = vec4(color.rgb * (light.ambient_color.rgb + light.color.rgb * diffuse), color.a);
+#endif
+#ifdef SPINE_EFFECTS
+    color = apply_effects(effects, color, input.color.a, texture, texture_sampler, dissolve_noise, dissolve_noise_sampler, input.uv);
+#endif
 #ifdef TONEMAP_IN_SHADER
     color = tonemapping::tone_mapping(color, view.color_grading);
 #endif
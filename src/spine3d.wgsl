@@ -0,0 +1,99 @@
+#import bevy_pbr::{
+    mesh_functions,
+    view_transformations::position_world_to_clip,
+    forward_io::VertexOutput as PbrVertexOutput,
+    pbr_fragment::pbr_input_from_standard_material,
+    pbr_functions::{apply_pbr_lighting, main_pass_post_lighting_processing},
+    pbr_types::STANDARD_MATERIAL_FLAGS_UNLIT_BIT,
+    pbr_bindings,
+}
+#import bevy_spine::effects::{SpineEffects, apply_effects}
+
+@group(2) @binding(100)
+var<uniform> effects: SpineEffects;
+@group(2) @binding(101)
+var dissolve_noise: texture_2d<f32>;
+@group(2) @binding(102)
+var dissolve_noise_sampler: sampler;
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+#ifdef VERTEX_TANGENTS
+    @location(3) tangent: vec4<f32>,
+#endif
+    @location(4) color: vec4<f32>,
+    @location(10) dark_color: vec4<f32>,
+};
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) world_position: vec4<f32>,
+    @location(1) world_normal: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+#ifdef VERTEX_TANGENTS
+    @location(3) world_tangent: vec4<f32>,
+#endif
+    @location(4) color: vec4<f32>,
+    @location(10) dark_color: vec4<f32>,
+};
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    var out: VertexOutput;
+    out.uv = vertex.uv;
+    let model = mesh_functions::get_world_from_local(vertex.instance_index);
+    out.world_position = mesh_functions::mesh_position_local_to_world(
+        model,
+        vec4<f32>(vertex.position, 1.0)
+    );
+    out.position = position_world_to_clip(out.world_position.xyz);
+    // spine meshes are flat, facing towards +z
+    out.world_normal = mesh_functions::mesh_normal_local_to_world(vec3<f32>(0.0, 0.0, 1.0), vertex.instance_index);
+#ifdef VERTEX_TANGENTS
+    out.world_tangent = mesh_functions::mesh_tangent_local_to_world(model, vertex.tangent, vertex.instance_index);
+#endif
+    out.color = vertex.color;
+    out.dark_color = vertex.dark_color;
+    return out;
+}
+
+@fragment
+fn fragment(
+    input: VertexOutput,
+    @builtin(front_facing) is_front: bool,
+) -> @location(0) vec4<f32> {
+    var pbr_vertex_output: PbrVertexOutput;
+    pbr_vertex_output.position = input.position;
+    pbr_vertex_output.world_position = input.world_position;
+    pbr_vertex_output.world_normal = input.world_normal;
+#ifdef VERTEX_UVS_A
+    pbr_vertex_output.uv = input.uv;
+#endif
+#ifdef VERTEX_TANGENTS
+    pbr_vertex_output.world_tangent = input.world_tangent;
+#endif
+#ifdef VERTEX_COLORS
+    // vertex colors are applied below, together with the dark color
+    pbr_vertex_output.color = vec4<f32>(1.0);
+#endif
+    var pbr_input = pbr_input_from_standard_material(pbr_vertex_output, is_front);
+
+    let tex_color = pbr_input.material.base_color;
+    pbr_input.material.base_color = vec4(
+        ((tex_color.a - 1.0) * input.dark_color.a + 1.0 - tex_color.rgb) * input.dark_color.rgb + tex_color.rgb * input.color.rgb,
+        tex_color.a * input.color.a,
+    );
+
+    var color: vec4<f32>;
+    if (pbr_input.material.flags & STANDARD_MATERIAL_FLAGS_UNLIT_BIT) == 0u {
+        color = apply_pbr_lighting(pbr_input);
+    } else {
+        color = pbr_input.material.base_color;
+    }
+#ifdef SPINE_EFFECTS
+    color = apply_effects(effects, color, input.color.a, pbr_bindings::base_color_texture, pbr_bindings::base_color_sampler, dissolve_noise, dissolve_noise_sampler, input.uv);
+#endif
+    return main_pass_post_lighting_processing(pbr_input, color);
+}
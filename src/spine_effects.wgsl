@@ -0,0 +1,65 @@
+#define_import_path bevy_spine::effects
+
+const FLAG_PREMULTIPLIED_ALPHA: u32 = 1u;
+const FLAG_DISSOLVE: u32 = 2u;
+
+struct SpineEffects {
+    flash_color: vec4<f32>,
+    outline_color: vec4<f32>,
+    dissolve_edge_color: vec4<f32>,
+    flash_amount: f32,
+    outline_width: f32,
+    dissolve_threshold: f32,
+    dissolve_edge_width: f32,
+    flags: u32,
+};
+
+// Applies flash, outline and dissolve to a color sampled from `texture` at `uv`. `alpha` is the
+// vertex alpha, applied to the outline.
+fn apply_effects(
+    effects: SpineEffects,
+    input_color: vec4<f32>,
+    alpha: f32,
+    texture: texture_2d<f32>,
+    texture_sampler: sampler,
+    noise_texture: texture_2d<f32>,
+    noise_sampler: sampler,
+    uv: vec2<f32>,
+) -> vec4<f32> {
+    let premultiplied_alpha = (effects.flags & FLAG_PREMULTIPLIED_ALPHA) != 0u;
+
+    // work with premultiplied colors, so the outline can be blended behind the color
+    var color = input_color;
+    if !premultiplied_alpha {
+        color = vec4(color.rgb * color.a, color.a);
+    }
+
+    color = vec4(mix(color.rgb, effects.flash_color.rgb * color.a, effects.flash_amount * effects.flash_color.a), color.a);
+
+    if effects.outline_width > 0.0 {
+        let offset = effects.outline_width / vec2<f32>(textureDimensions(texture));
+        var outline_alpha = 0.0;
+        for (var i = 0; i < 8; i += 1) {
+            let angle = f32(i) * 0.78539816;
+            let neighbor_uv = uv + vec2(cos(angle), sin(angle)) * offset;
+            outline_alpha = max(outline_alpha, textureSampleLevel(texture, texture_sampler, neighbor_uv, 0.0).a);
+        }
+        let outline = effects.outline_color.a * outline_alpha * alpha;
+        color += vec4(effects.outline_color.rgb * outline, outline) * (1.0 - color.a);
+    }
+
+    if (effects.flags & FLAG_DISSOLVE) != 0u {
+        let noise = textureSampleLevel(noise_texture, noise_sampler, uv, 0.0).r;
+        if noise < effects.dissolve_threshold {
+            discard;
+        }
+        if effects.dissolve_threshold > 0.0 && noise < effects.dissolve_threshold + effects.dissolve_edge_width {
+            color = vec4(mix(color.rgb, effects.dissolve_edge_color.rgb * color.a, effects.dissolve_edge_color.a), color.a);
+        }
+    }
+
+    if !premultiplied_alpha {
+        color = vec4(color.rgb / max(color.a, 0.0001), color.a);
+    }
+    return color;
+}
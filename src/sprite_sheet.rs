@@ -0,0 +1,185 @@
+//! Sprite sheets baked from Spine animations.
+//!
+//! See [`SpineSpriteSheet`].
+
+use std::collections::BTreeMap;
+
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext},
+    prelude::*,
+    reflect::TypePath,
+    sprite::Anchor,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::SpineLoaderError;
+
+/// A sprite sheet of Spine animations, loaded from `.spritesheet.ron` files written by the
+/// `bevy_spine bake` tool (see the `bake` feature).
+///
+/// Loading a sprite sheet also loads its image and a [`TextureAtlasLayout`] labeled `layout`,
+/// which requires Bevy's `SpritePlugin`. Without it, loading fails with
+/// [`SpineLoaderError::MissingTextureAtlasLayouts`].
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::SpineSpriteSheet;
+/// fn animate_sprite(sprite: &mut Sprite, sprite_sheet: &SpineSpriteSheet, time: f32) {
+///     if let Some(atlas) = &mut sprite.texture_atlas {
+///         atlas.index = sprite_sheet.animations["run"].frame_at(time, true);
+///     }
+/// }
+/// ```
+#[derive(Asset, Debug, TypePath)]
+pub struct SpineSpriteSheet {
+    pub image: Handle<Image>,
+    pub layout: Handle<TextureAtlasLayout>,
+    /// The size of every frame, in pixels.
+    pub frame_size: UVec2,
+    /// The position of the skeleton's origin in every frame, in pixels from the top left corner.
+    pub origin: Vec2,
+    /// The animations in this sprite sheet, keyed by name.
+    pub animations: BTreeMap<String, SpineSpriteSheetAnimation>,
+}
+
+impl SpineSpriteSheet {
+    /// A sprite using the sprite sheet, showing the first frame of `animation`.
+    ///
+    /// The sprite is anchored so that the skeleton's origin is at the entity's position.
+    pub fn sprite(&self, animation: &str) -> Sprite {
+        Sprite {
+            image: self.image.clone(),
+            texture_atlas: Some(TextureAtlas {
+                layout: self.layout.clone(),
+                index: self
+                    .animations
+                    .get(animation)
+                    .map(|animation| animation.first)
+                    .unwrap_or_default(),
+            }),
+            anchor: Anchor::Custom(Vec2::new(
+                self.origin.x / self.frame_size.x as f32 - 0.5,
+                0.5 - self.origin.y / self.frame_size.y as f32,
+            )),
+            ..Default::default()
+        }
+    }
+}
+
+/// The contents of a `.spritesheet.ron` file.
+///
+/// ```ron
+/// (
+///     image: "hero.png",
+///     size: (1024, 512),
+///     frame_size: (128, 128),
+///     origin: (64.0, 120.0),
+///     frames: [(x: 0, y: 0), (x: 128, y: 0)],
+///     animations: {
+///         "run": (first: 0, frames: 2, fps: 30.0, duration: 0.0667),
+///     },
+/// )
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SpineSpriteSheetFile {
+    /// The path of the sheet image, relative to this file.
+    pub image: String,
+    /// The size of the sheet image, in pixels.
+    pub size: (u32, u32),
+    /// The size of every frame, in pixels.
+    pub frame_size: (u32, u32),
+    /// The position of the skeleton's origin in every frame, in pixels from the top left corner.
+    pub origin: (f32, f32),
+    /// The top left corner of each frame, in layout order.
+    pub frames: Vec<SpineSpriteSheetFrame>,
+    pub animations: BTreeMap<String, SpineSpriteSheetAnimation>,
+}
+
+impl SpineSpriteSheetFile {
+    /// Creates a [`TextureAtlasLayout`] with a texture for each frame.
+    pub fn layout(&self) -> TextureAtlasLayout {
+        let mut layout = TextureAtlasLayout::new_empty(self.size.into());
+        for frame in self.frames.iter() {
+            let min = UVec2::new(frame.x, frame.y);
+            layout.add_texture(URect::from_corners(min, min + UVec2::from(self.frame_size)));
+        }
+        layout
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SpineSpriteSheetFrame {
+    pub x: u32,
+    pub y: u32,
+}
+
+/// An animation sampled into consecutive frames of a [`SpineSpriteSheet`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SpineSpriteSheetAnimation {
+    /// The layout index of the first frame.
+    pub first: usize,
+    /// The number of frames, sampled every `1. / fps` seconds.
+    pub frames: usize,
+    pub fps: f32,
+    /// The duration of the Spine animation, in seconds.
+    pub duration: f32,
+}
+
+impl SpineSpriteSheetAnimation {
+    /// The layout index of the frame shown `time` seconds into the animation. If `looping` is
+    /// `false`, the last frame is held after the animation ends.
+    pub fn frame_at(&self, time: f32, looping: bool) -> usize {
+        let time = if looping && self.duration > 0. {
+            time.rem_euclid(self.duration)
+        } else {
+            time.max(0.)
+        };
+        let frame = (time * self.fps) as usize;
+        self.first + frame.min(self.frames.saturating_sub(1))
+    }
+}
+
+pub(crate) struct SpineSpriteSheetLoader {
+    /// Whether `Assets<TextureAtlasLayout>` exists to hold the sheet's layout.
+    pub(crate) texture_atlas_layouts: bool,
+}
+
+impl SpineSpriteSheetLoader {
+    pub(crate) const EXTENSIONS: &'static [&'static str] = &["spritesheet.ron"];
+}
+
+impl AssetLoader for SpineSpriteSheetLoader {
+    type Asset = SpineSpriteSheet;
+    type Settings = ();
+    type Error = SpineLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        if !self.texture_atlas_layouts {
+            return Err(SpineLoaderError::MissingTextureAtlasLayouts);
+        }
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let file: SpineSpriteSheetFile = ron::de::from_bytes(&bytes)?;
+        let image_path = load_context
+            .path()
+            .parent()
+            .map(|parent| parent.join(&file.image))
+            .unwrap_or_else(|| file.image.clone().into());
+        Ok(SpineSpriteSheet {
+            image: load_context.load(image_path),
+            layout: load_context.add_labeled_asset("layout".to_owned(), file.layout()),
+            frame_size: file.frame_size.into(),
+            origin: file.origin.into(),
+            animations: file.animations,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        Self::EXTENSIONS
+    }
+}
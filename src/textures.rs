@@ -1,14 +1,24 @@
 //! Events related to textures loaded by Spine.
 
-use std::sync::{Arc, Mutex};
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
 
-use bevy::prelude::*;
+use bevy::{image::ImageLoaderSettings, prelude::*};
 use rusty_spine::atlas::{AtlasFilter, AtlasWrap};
 
-use crate::Atlas;
+use crate::{normal_maps::normal_map_path, Atlas};
 
-#[derive(Debug)]
-pub struct SpineTexture(pub String);
+/// The renderer object of Spine atlas pages, identifying the texture to draw with.
+#[derive(Debug, Clone)]
+pub enum SpineTexture {
+    /// An atlas page texture, loaded from this asset path.
+    Path(String),
+    /// A Bevy image, used by attachments created with
+    /// [`SpineImageAttachment`](`crate::SpineImageAttachment`).
+    Image(Handle<Image>),
+}
 
 #[derive(Debug)]
 struct SpineTextureInternal {
@@ -54,6 +64,7 @@ pub struct SpineTextureDisposeEvent {
 #[derive(Default)]
 pub(crate) struct SpineTexturesData {
     handles: Vec<(String, Handle<Image>)>,
+    normal_maps: HashMap<String, Handle<Image>>,
     remember: Vec<SpineTextureInternal>,
     forget: Vec<String>,
 }
@@ -75,17 +86,16 @@ impl SpineTextures {
                     v_wrap: page.v_wrap(),
                 },
             });
-            page.renderer_object().set(SpineTexture(path.to_owned()));
+            page.renderer_object()
+                .set(SpineTexture::Path(path.to_owned()));
         });
 
         let data3 = data.clone();
         rusty_spine::extension::set_dispose_texture_cb(move |page| unsafe {
-            data3.lock().unwrap().forget.push(
-                page.renderer_object()
-                    .get_unchecked::<SpineTexture>()
-                    .0
-                    .clone(),
-            );
+            if let SpineTexture::Path(path) = page.renderer_object().get_unchecked::<SpineTexture>()
+            {
+                data3.lock().unwrap().forget.push(path.clone());
+            }
             page.renderer_object().dispose::<SpineTexture>();
         });
 
@@ -114,6 +124,7 @@ impl SpineTextures {
             }
         }
         while let Some(texture_path) = data.forget.pop() {
+            data.normal_maps.remove(&texture_path);
             if let Some(index) = data.handles.iter().position(|i| i.0 == texture_path) {
                 dispose_events.send(SpineTextureDisposeEvent {
                     path: texture_path,
@@ -123,6 +134,30 @@ impl SpineTextures {
             }
         }
     }
+
+    /// Returns the normal map of an atlas page once it has loaded, loading it if necessary.
+    ///
+    /// Pages without a normal map never return one. Normal maps are released along with their
+    /// pages.
+    pub(crate) fn normal_map(
+        &self,
+        asset_server: &AssetServer,
+        page_path: &str,
+    ) -> Option<Handle<Image>> {
+        let mut data = self.data.lock().unwrap();
+        let handle = data
+            .normal_maps
+            .entry(page_path.to_owned())
+            .or_insert_with(|| {
+                asset_server.load_with_settings(
+                    normal_map_path(page_path),
+                    |settings: &mut ImageLoaderSettings| settings.is_srgb = false,
+                )
+            });
+        asset_server
+            .is_loaded_with_dependencies(handle.id())
+            .then(|| handle.clone())
+    }
 }
 
 fn find_matching_atlas(atlases: &Assets<Atlas>, atlas_address: usize) -> Option<Handle<Atlas>> {
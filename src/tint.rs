@@ -0,0 +1,161 @@
+//! Tinting skeletons and slots on top of animated colors.
+//!
+//! See [`SpineTint`] and [`SpineSlotTint`].
+
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use rusty_spine::Color as SpineColor;
+
+use crate::Spine;
+
+/// Tints every slot of a [`Spine`], multiplied with the animated slot colors.
+///
+/// Tints are applied after animations every frame and removed again once meshes are updated, so
+/// they never compound or get overwritten by color timelines. Both colors are [`Reflect`], so
+/// they can be tweened or edited in an inspector.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpineTint};
+/// fn hit_flash(mut tint_query: Query<&mut SpineTint>, time: Res<Time>) {
+///     for mut tint in tint_query.iter_mut() {
+///         // fade from red back to white
+///         let fade = (tint.color.to_srgba().green + time.delta_secs() * 4.).min(1.);
+///         tint.color = Color::srgb(1., fade, fade);
+///     }
+/// }
+/// ```
+#[derive(Component, Reflect, Debug, Clone, Copy, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+#[require(SpineTintState)]
+pub struct SpineTint {
+    /// Multiplied with each slot's color (default: white).
+    pub color: Color,
+    /// Multiplied with each slot's dark color, only affecting slots which use two color tinting
+    /// (default: white).
+    pub dark_color: Color,
+}
+
+impl Default for SpineTint {
+    fn default() -> Self {
+        Self {
+            color: Color::WHITE,
+            dark_color: Color::WHITE,
+        }
+    }
+}
+
+impl SpineTint {
+    pub fn new(color: Color) -> Self {
+        Self {
+            color,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_dark_color(mut self, dark_color: Color) -> Self {
+        self.dark_color = dark_color;
+        self
+    }
+}
+
+/// Tints individual slots of a [`Spine`], keyed by slot name.
+///
+/// Applied the same way as [`SpineTint`], and combined with it if both are present. Slots missing
+/// from the skeleton are ignored.
+///
+/// ```
+/// # use bevy::prelude::*;
+/// # use bevy_spine::{prelude::*, SpineSlotTint, SpineTint};
+/// # fn doc(mut commands: Commands, spine_entity: Entity) {
+/// commands
+///     .entity(spine_entity)
+///     .insert(SpineSlotTint::default().with("gun", SpineTint::new(Color::srgb(1., 0.5, 0.5))));
+/// # }
+/// ```
+#[derive(Component, Reflect, Default, Debug, Clone, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq)]
+#[require(SpineTintState)]
+pub struct SpineSlotTint(pub HashMap<String, SpineTint>);
+
+impl SpineSlotTint {
+    pub fn with(mut self, slot: &str, tint: SpineTint) -> Self {
+        self.0.insert(slot.to_owned(), tint);
+        self
+    }
+}
+
+/// The untinted slot colors, restored after meshes are updated.
+#[derive(Component, Default, Debug)]
+pub(crate) struct SpineTintState {
+    restore: Vec<(usize, SpineColor, Option<SpineColor>)>,
+}
+
+const WHITE: SpineColor = SpineColor::new_rgba(1., 1., 1., 1.);
+
+fn to_spine_color(color: Color) -> SpineColor {
+    let color = color.to_srgba();
+    SpineColor::new_rgba(color.red, color.green, color.blue, color.alpha)
+}
+
+pub(crate) fn spine_apply_tints(
+    mut spine_query: Query<(
+        &mut Spine,
+        Option<&SpineTint>,
+        Option<&SpineSlotTint>,
+        &mut SpineTintState,
+    )>,
+) {
+    for (mut spine, tint, slot_tint, mut state) in spine_query.iter_mut() {
+        let state = &mut *state;
+        state.restore.clear();
+        for mut slot in spine.skeleton.slots_mut() {
+            let slot_tint = slot_tint.and_then(|slot_tint| slot_tint.0.get(slot.data().name()));
+            let (color, dark_color) = tint.into_iter().chain(slot_tint).fold(
+                (WHITE, WHITE),
+                |(color, dark_color), tint| {
+                    (
+                        color * to_spine_color(tint.color),
+                        dark_color * to_spine_color(tint.dark_color),
+                    )
+                },
+            );
+            if color == WHITE && dark_color == WHITE {
+                continue;
+            }
+            state
+                .restore
+                .push((slot.data().index(), slot.color(), slot.dark_color()));
+            *slot.color_mut() *= color;
+            unsafe {
+                let c_dark_color = (*slot.c_ptr()).darkColor;
+                if !c_dark_color.is_null() {
+                    *c_dark_color.cast::<SpineColor>() *= dark_color;
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn spine_restore_tints(mut spine_query: Query<(&mut Spine, &mut SpineTintState)>) {
+    for (mut spine, mut state) in spine_query.iter_mut() {
+        if state.restore.is_empty() {
+            continue;
+        }
+        for (index, color, dark_color) in state.restore.drain(..) {
+            let Some(mut slot) = spine.skeleton.slot_at_index_mut(index) else {
+                continue;
+            };
+            *slot.color_mut() = color;
+            if let Some(dark_color) = dark_color {
+                unsafe {
+                    let c_dark_color = (*slot.c_ptr()).darkColor;
+                    if !c_dark_color.is_null() {
+                        *c_dark_color.cast::<SpineColor>() = dark_color;
+                    }
+                }
+            }
+        }
+    }
+}
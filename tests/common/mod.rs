@@ -0,0 +1,81 @@
+//! Synthetic skeletons shared by the integration tests.
+
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use bevy::{prelude::*, time::TimeUpdateStrategy};
+use bevy_spine::{prelude::*, Atlas, SkeletonJson, SpineDrawer};
+
+/// A single 16x16 region named `square`.
+const ATLAS: &str = "synthetic.png
+size: 16, 16
+filter: Linear, Linear
+square
+bounds: 0, 0, 16, 16
+";
+
+/// Spawns a skeleton loaded from `json` with the synthetic atlas, along with `bundle`, and updates
+/// the app until it is ready. Time advances by 1/60 s per update.
+pub fn spawn_synthetic_skeleton(
+    json: &str,
+    drawer: SpineDrawer,
+    bundle: impl Bundle,
+) -> (App, Entity) {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin::default(),
+        TransformPlugin,
+        HierarchyPlugin,
+    ))
+    .init_asset::<Mesh>()
+    .init_asset::<Image>()
+    .init_asset::<Shader>()
+    .add_plugins(SpinePlugin)
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        1. / 60.,
+    )));
+
+    let atlas = Atlas {
+        atlas: Arc::new(bevy_spine::rusty_spine::Atlas::new(ATLAS.as_bytes(), "").unwrap()),
+    };
+    let json = SkeletonJson {
+        json: json.as_bytes().to_vec(),
+    };
+    let atlas_handle = app.world_mut().resource_mut::<Assets<Atlas>>().add(atlas);
+    let json_handle = app
+        .world_mut()
+        .resource_mut::<Assets<SkeletonJson>>()
+        .add(json);
+    let skeleton_handle = app
+        .world_mut()
+        .resource_mut::<Assets<SkeletonData>>()
+        .add(SkeletonData::new_from_json(json_handle, atlas_handle));
+    let entity = app
+        .world_mut()
+        .spawn((
+            SpineBundle {
+                skeleton: skeleton_handle.into(),
+                settings: SpineSettings {
+                    drawer,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            bundle,
+        ))
+        .id();
+
+    let started = Instant::now();
+    while app.world().get::<Spine>(entity).is_none() {
+        assert!(
+            started.elapsed() < Duration::from_secs(30),
+            "skeleton failed to load"
+        );
+        app.update();
+    }
+    app.update();
+    (app, entity)
+}
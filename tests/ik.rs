@@ -0,0 +1,64 @@
+//! Checks that IK targets follow entities relative to the animated pose.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_spine::{prelude::*, SpineDrawer, SpineIkTargets};
+use common::spawn_synthetic_skeleton;
+
+/// A `body` bone walking 100 units along x every second, carrying two IK target bones.
+const SKELETON: &str = r#"{
+    "skeleton": { "spine": "4.2.0" },
+    "bones": [
+        { "name": "root" },
+        { "name": "body", "parent": "root" },
+        { "name": "arm", "parent": "body", "length": 20 },
+        { "name": "leg", "parent": "body", "length": 20 },
+        { "name": "arm-target", "parent": "body", "x": 20 },
+        { "name": "leg-target", "parent": "body", "y": -20 }
+    ],
+    "ik": [
+        { "name": "arm-ik", "bones": ["arm"], "target": "arm-target" },
+        { "name": "leg-ik", "bones": ["leg"], "target": "leg-target" }
+    ],
+    "animations": {
+        "walk": {
+            "bones": {
+                "body": { "translate": [{ "x": 0 }, { "time": 1, "x": 100 }] }
+            }
+        }
+    }
+}"#;
+
+#[test]
+fn follows_targets_after_animation() {
+    let (mut app, entity) = spawn_synthetic_skeleton(SKELETON, SpineDrawer::None, ());
+    let arm_target = app.world_mut().spawn(Transform::from_xyz(30., 40., 0.)).id();
+    let leg_target = app.world_mut().spawn(Transform::from_xyz(-10., -50., 0.)).id();
+    app.world_mut().entity_mut(entity).insert(SpineIkTargets::new([
+        SpineIkTarget::new("arm-ik", arm_target),
+        SpineIkTarget::new("leg-ik", leg_target),
+    ]));
+    app.world_mut()
+        .get_mut::<Spine>(entity)
+        .unwrap()
+        .animation_state
+        .set_animation_by_name(0, "walk", true)
+        .unwrap();
+    for _ in 0..30 {
+        app.update();
+    }
+    let spine = app.world().get::<Spine>(entity).unwrap();
+    // the body moves every frame, so the targets are only reached if they are placed relative to
+    // this frame's pose of the body
+    let body = spine.skeleton.find_bone("body").unwrap();
+    assert!(body.world_x() > 40., "{}", body.world_x());
+    for (bone, expected) in [
+        ("arm-target", Vec2::new(30., 40.)),
+        ("leg-target", Vec2::new(-10., -50.)),
+    ] {
+        let bone = spine.skeleton.find_bone(bone).unwrap();
+        let position = Vec2::new(bone.world_x(), bone.world_y());
+        assert!(position.distance(expected) < 0.01, "{position}");
+    }
+}
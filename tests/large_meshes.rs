@@ -0,0 +1,110 @@
+//! Regression test for combined meshes with more vertices than 16-bit indices can address.
+
+mod common;
+
+use bevy::{
+    prelude::*,
+    render::mesh::{Indices, VertexAttributeValues},
+};
+use bevy_spine::{prelude::*, SpineDrawer};
+use common::spawn_synthetic_skeleton;
+
+/// Each slot holds a `GRID` x `GRID` mesh, so the combined mesh has 67,600 vertices.
+const GRID: usize = 130;
+const SLOTS: usize = 4;
+
+fn synthetic_skeleton_json() -> String {
+    let mut uvs = vec![];
+    let mut vertices = vec![];
+    for y in 0..GRID {
+        for x in 0..GRID {
+            let (u, v) = (x as f32 / (GRID - 1) as f32, y as f32 / (GRID - 1) as f32);
+            uvs.extend([u, v]);
+            vertices.extend([u * 100., v * 100.]);
+        }
+    }
+    let mut triangles = vec![];
+    for y in 0..GRID - 1 {
+        for x in 0..GRID - 1 {
+            let i = y * GRID + x;
+            triangles.extend([i, i + 1, i + GRID, i + 1, i + GRID + 1, i + GRID]);
+        }
+    }
+    let mesh = format!(
+        r#"{{"type":"mesh","uvs":{uvs:?},"triangles":{triangles:?},"vertices":{vertices:?},"hull":4}}"#
+    );
+    let slots = (0..SLOTS)
+        .map(|slot| format!(r#"{{"name":"slot{slot}","bone":"root","attachment":"square"}}"#))
+        .collect::<Vec<_>>()
+        .join(",");
+    let attachments = (0..SLOTS)
+        .map(|slot| format!(r#""slot{slot}":{{"square":{mesh}}}"#))
+        .collect::<Vec<_>>()
+        .join(",");
+    format!(
+        r#"{{"skeleton":{{"spine":"4.2.0"}},"bones":[{{"name":"root"}}],"slots":[{slots}],"skins":[{{"name":"default","attachments":{{{attachments}}}}}]}}"#
+    )
+}
+
+fn spawn_grid_skeleton() -> App {
+    spawn_synthetic_skeleton(&synthetic_skeleton_json(), SpineDrawer::Combined, ()).0
+}
+
+/// Returns the vertex count and indices of every non-empty Spine mesh.
+fn spine_meshes(app: &mut App) -> Vec<(usize, Indices)> {
+    let handles = app
+        .world_mut()
+        .query::<&SpineMesh>()
+        .iter(app.world())
+        .map(|spine_mesh| spine_mesh.handle.clone())
+        .collect::<Vec<_>>();
+    let meshes = app.world().resource::<Assets<Mesh>>();
+    handles
+        .iter()
+        .filter_map(|handle| meshes.get(handle))
+        .filter_map(|mesh| {
+            let Some(VertexAttributeValues::Float32x2(positions)) =
+                mesh.attribute(Mesh::ATTRIBUTE_POSITION.id)
+            else {
+                return None;
+            };
+            Some((positions.len(), mesh.indices()?.clone()))
+        })
+        .collect()
+}
+
+#[test]
+fn combined_mesh_uses_32_bit_indices() {
+    let mut app = spawn_grid_skeleton();
+    let meshes = spine_meshes(&mut app);
+    assert_eq!(meshes.len(), 1);
+    let (vertex_count, indices) = &meshes[0];
+    assert_eq!(*vertex_count, GRID * GRID * SLOTS);
+    let Indices::U32(indices) = indices else {
+        panic!("expected 32-bit indices for {vertex_count} vertices");
+    };
+    assert_eq!(indices.len(), (GRID - 1) * (GRID - 1) * 6 * SLOTS);
+    assert_eq!(
+        indices.iter().max().copied(),
+        Some(*vertex_count as u32 - 1)
+    );
+}
+
+#[test]
+fn separated_meshes_use_16_bit_indices() {
+    let mut app = spawn_grid_skeleton();
+    for mut settings in app
+        .world_mut()
+        .query::<&mut SpineSettings>()
+        .iter_mut(app.world_mut())
+    {
+        settings.drawer = SpineDrawer::Separated;
+    }
+    app.update();
+    let meshes = spine_meshes(&mut app);
+    assert_eq!(meshes.len(), SLOTS);
+    for (vertex_count, indices) in meshes {
+        assert_eq!(vertex_count, GRID * GRID);
+        assert!(matches!(indices, Indices::U16(_)));
+    }
+}
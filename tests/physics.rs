@@ -0,0 +1,103 @@
+//! Checks that entity movement, wind and gravity overrides feed into physics constraints, and
+//! that teleports don't.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_spine::{prelude::*, SpineDrawer, SpinePhysics, SpineTeleport};
+use common::spawn_synthetic_skeleton;
+
+/// A `tail` bone 50 units right of the root, simulated on both axes without wind or gravity.
+const SKELETON: &str = r#"{
+    "skeleton": { "spine": "4.2.0" },
+    "bones": [{ "name": "root" }, { "name": "tail", "parent": "root", "x": 50 }],
+    "physics": [{ "name": "tail", "bone": "tail", "x": 1, "y": 1 }]
+}"#;
+
+fn spawn_tail(spine_physics: SpinePhysics) -> (App, Entity) {
+    let (mut app, entity) = spawn_synthetic_skeleton(SKELETON, SpineDrawer::None, spine_physics);
+    for _ in 0..10 {
+        app.update();
+    }
+    (app, entity)
+}
+
+/// The position of the `tail` bone in skeleton space.
+fn tail_position(app: &App, entity: Entity) -> Vec2 {
+    let spine = app.world().get::<Spine>(entity).unwrap();
+    let tail = spine.skeleton.find_bone("tail").unwrap();
+    Vec2::new(tail.world_x(), tail.world_y())
+}
+
+fn move_to(app: &mut App, entity: Entity, translation: Vec3) {
+    app.world_mut()
+        .get_mut::<Transform>(entity)
+        .unwrap()
+        .translation = translation;
+}
+
+#[test]
+fn movement_moves_physics_bones() {
+    let (mut app, entity) = spawn_tail(SpinePhysics::default());
+    assert!(tail_position(&app, entity).distance(Vec2::new(50., 0.)) < 1e-3);
+    move_to(&mut app, entity, Vec3::new(100., 0., 0.));
+    for _ in 0..2 {
+        app.update();
+    }
+    assert!(tail_position(&app, entity).distance(Vec2::new(50., 0.)) > 1.);
+}
+
+#[test]
+fn teleports_do_not_move_physics_bones() {
+    let (mut app, entity) = spawn_tail(SpinePhysics::default());
+    move_to(&mut app, entity, Vec3::new(1000., 0., 0.));
+    app.world_mut().entity_mut(entity).insert(SpineTeleport);
+    for _ in 0..3 {
+        app.update();
+        assert!(tail_position(&app, entity).distance(Vec2::new(50., 0.)) < 1e-3);
+    }
+}
+
+#[test]
+fn detects_teleports_by_distance() {
+    let (mut app, entity) = spawn_tail(SpinePhysics::default().with_teleport_distance(500.));
+    move_to(&mut app, entity, Vec3::new(1000., 0., 0.));
+    for _ in 0..3 {
+        app.update();
+        assert!(tail_position(&app, entity).distance(Vec2::new(50., 0.)) < 1e-3);
+    }
+}
+
+#[test]
+fn overrides_wind_and_gravity() {
+    for spine_physics in [
+        SpinePhysics::default().with_wind(100.),
+        SpinePhysics::default().with_gravity(100.),
+    ] {
+        let (mut app, entity) = spawn_tail(spine_physics);
+        for _ in 0..10 {
+            app.update();
+        }
+        assert!(tail_position(&app, entity).distance(Vec2::new(50., 0.)) > 1.);
+    }
+}
+
+#[test]
+fn restores_wind_and_gravity() {
+    let (mut app, entity) = spawn_tail(SpinePhysics::default().with_wind(100.).with_gravity(100.));
+    let wind_and_gravity = |app: &App| {
+        let spine = app.world().get::<Spine>(entity).unwrap();
+        let tail = spine.skeleton.find_physics_constraint("tail").unwrap();
+        (tail.wind(), tail.gravity())
+    };
+    assert_eq!(wind_and_gravity(&app), (100., 100.));
+    {
+        let mut spine_physics = app.world_mut().get_mut::<SpinePhysics>(entity).unwrap();
+        spine_physics.wind = None;
+    }
+    app.update();
+    assert_eq!(wind_and_gravity(&app), (0., 100.));
+    app.world_mut().entity_mut(entity).remove::<SpinePhysics>();
+    app.update();
+    assert_eq!(wind_and_gravity(&app), (0., 0.));
+}
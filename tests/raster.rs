@@ -0,0 +1,151 @@
+//! Checks the CPU rasterizer against the color math of `spine.wgsl` and the built-in blend
+//! states.
+
+use bevy::prelude::*;
+use bevy_spine::{
+    raster::{SpineRaster, SpineRasterRenderable, SpineRasterTexture, SpineRasterTextures},
+    rusty_spine::BlendMode,
+    textures::SpineTexture,
+};
+
+const PAGE: &str = "page.png";
+
+/// A quad covering a 4x4 raster.
+struct Quad {
+    color: [f32; 4],
+    dark_color: [f32; 4],
+    blend_mode: BlendMode,
+    premultiplied_alpha: bool,
+    texture: SpineTexture,
+}
+
+impl Quad {
+    fn new(color: [f32; 4]) -> Self {
+        Self {
+            color,
+            dark_color: [0., 0., 0., 0.],
+            blend_mode: BlendMode::Normal,
+            premultiplied_alpha: false,
+            texture: SpineTexture::Path(PAGE.to_owned()),
+        }
+    }
+}
+
+impl SpineRasterRenderable for Quad {
+    fn vertices(&self) -> &[[f32; 2]] {
+        &[[-2., -2.], [2., -2.], [2., 2.], [-2., 2.]]
+    }
+
+    fn uvs(&self) -> &[[f32; 2]] {
+        &[[0., 1.], [1., 1.], [1., 0.], [0., 0.]]
+    }
+
+    fn index_count(&self) -> usize {
+        6
+    }
+
+    fn index(&self, index: usize) -> usize {
+        [0, 1, 2, 2, 3, 0][index]
+    }
+
+    fn color(&self, _vertex: usize) -> [f32; 4] {
+        self.color
+    }
+
+    fn dark_color(&self, _vertex: usize) -> [f32; 4] {
+        self.dark_color
+    }
+
+    fn blend_mode(&self) -> BlendMode {
+        self.blend_mode
+    }
+
+    fn premultiplied_alpha(&self) -> bool {
+        self.premultiplied_alpha
+    }
+
+    fn texture(&self) -> Option<&SpineTexture> {
+        Some(&self.texture)
+    }
+}
+
+fn textures(texel: [u8; 4], premultiplied_alpha: bool) -> SpineRasterTextures {
+    let mut textures = SpineRasterTextures::default();
+    textures.insert_page(
+        PAGE,
+        SpineRasterTexture::new(1, 1, &texel, premultiplied_alpha),
+    );
+    textures
+}
+
+fn draw(quad: Quad, textures: &SpineRasterTextures, clear: LinearRgba) -> SpineRaster {
+    let mut raster = SpineRaster::new(4, 4);
+    raster.clear(clear);
+    raster.draw(&[quad], textures);
+    raster
+}
+
+#[test]
+fn tints_texture() {
+    let raster = draw(
+        Quad::new([1., 0., 0., 1.]),
+        &textures([255, 255, 255, 255], false),
+        LinearRgba::NONE,
+    );
+    assert_eq!(raster.to_rgba8(), [255u8, 0, 0, 255].repeat(16));
+}
+
+#[test]
+fn applies_two_color_tint() {
+    let quad = Quad {
+        dark_color: [0., 0., 1., 1.],
+        ..Quad::new([1., 1., 1., 1.])
+    };
+    let raster = draw(quad, &textures([0, 0, 0, 255], false), LinearRgba::NONE);
+    assert_eq!(raster.to_rgba8(), [0u8, 0, 255, 255].repeat(16));
+}
+
+#[test]
+fn blends_additively() {
+    let quad = Quad {
+        blend_mode: BlendMode::Additive,
+        ..Quad::new([1., 0., 0., 0.5])
+    };
+    let raster = draw(
+        quad,
+        &textures([255, 255, 255, 255], false),
+        LinearRgba::GREEN,
+    );
+    let pixel = raster.pixel(1, 1);
+    assert!((pixel.red - 0.5).abs() < 1e-5);
+    assert_eq!(pixel.green, 1.);
+    assert_eq!(pixel.alpha, 1.);
+}
+
+#[test]
+fn converts_premultiplied_alpha_pages() {
+    let quad = Quad {
+        premultiplied_alpha: true,
+        ..Quad::new([1., 1., 1., 1.])
+    };
+    let raster = draw(quad, &textures([128, 0, 0, 128], true), LinearRgba::NONE);
+    let pixel = raster.pixel(2, 2);
+    assert!((pixel.alpha - 128. / 255.).abs() < 1e-5);
+    assert!((pixel.red - pixel.alpha).abs() < 1e-5);
+    assert_eq!(raster.to_unpremultiplied_rgba8()[..3], [255u8, 0, 0]);
+}
+
+#[test]
+fn draws_shared_edges_once() {
+    let quad = Quad::new([1., 1., 1., 0.5]);
+    let raster = draw(
+        quad,
+        &textures([255, 255, 255, 255], false),
+        LinearRgba::NONE,
+    );
+    for y in 0..4 {
+        for x in 0..4 {
+            assert_eq!(raster.pixel(x, y).alpha, 0.5);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Checks that root motion is extracted across animation loops.
+
+mod common;
+
+use bevy::prelude::*;
+use bevy_spine::{prelude::*, SpineDrawer, SpineRootMotion};
+use common::spawn_synthetic_skeleton;
+
+/// A `body` bone walking 100 units along x every second, and an `arm` bone swinging.
+const SKELETON: &str = r#"{
+    "skeleton": { "spine": "4.2.0" },
+    "bones": [
+        { "name": "root" },
+        { "name": "body", "parent": "root" },
+        { "name": "arm", "parent": "body", "x": 10 }
+    ],
+    "animations": {
+        "walk": {
+            "bones": {
+                "body": { "translate": [{ "x": 0 }, { "time": 1, "x": 100 }] },
+                "arm": { "rotate": [{ "value": 0 }, { "time": 1, "value": 90 }] }
+            }
+        }
+    }
+}"#;
+
+#[test]
+fn extracts_motion_across_loops() {
+    let (mut app, entity) =
+        spawn_synthetic_skeleton(SKELETON, SpineDrawer::None, SpineRootMotion::new("body"));
+    app.world_mut()
+        .get_mut::<Spine>(entity)
+        .unwrap()
+        .animation_state
+        .set_animation_by_name(0, "walk", true)
+        .unwrap();
+    for _ in 0..150 {
+        app.update();
+    }
+    let transform = app.world().get::<Transform>(entity).unwrap();
+    assert!(
+        (transform.translation.x - 250.).abs() < 5.,
+        "{}",
+        transform.translation.x
+    );
+    let spine = app.world().get::<Spine>(entity).unwrap();
+    let body = spine.skeleton.find_bone("body").unwrap();
+    assert_eq!(body.x(), 0.);
+    // the arm keeps its own animation, which is 2.5 seconds in
+    let arm = spine.skeleton.find_bone("arm").unwrap();
+    assert!((arm.rotation() - 45.).abs() < 5., "{}", arm.rotation());
+}
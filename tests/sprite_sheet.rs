@@ -0,0 +1,99 @@
+//! Checks sprite sheet frame lookup and loading.
+
+use std::time::{Duration, Instant};
+
+use bevy::{asset::LoadState, prelude::*};
+use bevy_spine::{prelude::*, SpineSpriteSheet, SpineSpriteSheetAnimation};
+
+/// Four frames at 10 fps, starting at layout index 2.
+const ANIMATION: SpineSpriteSheetAnimation = SpineSpriteSheetAnimation {
+    first: 2,
+    frames: 4,
+    fps: 10.,
+    duration: 0.4,
+};
+
+#[test]
+fn frame_at_samples_frames() {
+    assert_eq!(ANIMATION.frame_at(0., false), 2);
+    assert_eq!(ANIMATION.frame_at(0.15, false), 3);
+    assert_eq!(ANIMATION.frame_at(0.39, false), 5);
+    assert_eq!(ANIMATION.frame_at(-1., false), 2);
+}
+
+#[test]
+fn frame_at_wraps_looping_animations() {
+    assert_eq!(ANIMATION.frame_at(0.45, true), 2);
+    assert_eq!(ANIMATION.frame_at(0.75, true), 5);
+    assert_eq!(ANIMATION.frame_at(-0.05, true), 5);
+}
+
+#[test]
+fn frame_at_holds_last_frame() {
+    assert_eq!(ANIMATION.frame_at(0.45, false), 5);
+    assert_eq!(ANIMATION.frame_at(100., false), 5);
+}
+
+#[test]
+fn frame_at_handles_empty_animations() {
+    let single_frame = SpineSpriteSheetAnimation {
+        frames: 1,
+        duration: 0.,
+        ..ANIMATION
+    };
+    assert_eq!(single_frame.frame_at(0., true), 2);
+    assert_eq!(single_frame.frame_at(1., true), 2);
+    let no_frames = SpineSpriteSheetAnimation {
+        frames: 0,
+        ..single_frame
+    };
+    assert_eq!(no_frames.frame_at(1., false), 2);
+}
+
+#[test]
+fn loading_without_sprite_plugin_fails() {
+    let dir = std::env::temp_dir().join(format!("bevy_spine_sprite_sheet_{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(
+        dir.join("hero.spritesheet.ron"),
+        r#"(image: "hero.png", size: (1, 1), frame_size: (1, 1), origin: (0.0, 0.0), frames: [(x: 0, y: 0)], animations: {})"#,
+    )
+    .unwrap();
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        AssetPlugin {
+            file_path: dir.to_string_lossy().into_owned(),
+            ..Default::default()
+        },
+        TransformPlugin,
+        HierarchyPlugin,
+    ))
+    .init_asset::<Mesh>()
+    .init_asset::<Image>()
+    .init_asset::<Shader>()
+    .add_plugins(SpinePlugin);
+    app.finish();
+    app.cleanup();
+
+    let handle: Handle<SpineSpriteSheet> = app
+        .world()
+        .resource::<AssetServer>()
+        .load("hero.spritesheet.ron");
+    let started = Instant::now();
+    let error = loop {
+        assert!(
+            started.elapsed() < Duration::from_secs(30),
+            "sprite sheet neither loaded nor failed"
+        );
+        app.update();
+        match app.world().resource::<AssetServer>().load_state(&handle) {
+            LoadState::Failed(error) => break error,
+            LoadState::Loaded => panic!("sprite sheet loaded without SpritePlugin"),
+            _ => {}
+        }
+    };
+    let _ = std::fs::remove_dir_all(&dir);
+    assert!(error.to_string().contains("SpritePlugin"), "{error}");
+}